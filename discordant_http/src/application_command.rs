@@ -0,0 +1,83 @@
+use discordant_types::{ApplicationCommand, ApplicationMarker, CommandMarker, GuildMarker, Id};
+
+use crate::{error::HttpError, Client, Request};
+
+/// Commands registered globally, or in a single guild when `guild_id` is given.
+impl Client {
+    pub async fn get_commands(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+    ) -> Result<Vec<ApplicationCommand<'static>>, HttpError> {
+        self.request(Request::get(commands_path(application_id, guild_id)))
+            .await
+    }
+
+    pub async fn get_command(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        command_id: Id<CommandMarker>,
+    ) -> Result<ApplicationCommand<'static>, HttpError> {
+        let path = format!("{}/{command_id}", commands_path(application_id, guild_id));
+
+        self.request(Request::get(path)).await
+    }
+
+    /// Creates a command, or replaces the one with the same name and type.
+    pub async fn create_command(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        command: &ApplicationCommand<'_>,
+    ) -> Result<ApplicationCommand<'static>, HttpError> {
+        let path = commands_path(application_id, guild_id);
+
+        self.request(Request::post(path).json(command)?).await
+    }
+
+    pub async fn edit_command(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        command_id: Id<CommandMarker>,
+        command: &ApplicationCommand<'_>,
+    ) -> Result<ApplicationCommand<'static>, HttpError> {
+        let path = format!("{}/{command_id}", commands_path(application_id, guild_id));
+
+        self.request(Request::patch(path).json(command)?).await
+    }
+
+    pub async fn delete_command(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        command_id: Id<CommandMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("{}/{command_id}", commands_path(application_id, guild_id));
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    /// Replaces every command with `commands`, deleting any not in the list.
+    pub async fn set_commands(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        commands: &[ApplicationCommand<'_>],
+    ) -> Result<Vec<ApplicationCommand<'static>>, HttpError> {
+        let path = commands_path(application_id, guild_id);
+
+        self.request(Request::put(path).json(commands)?).await
+    }
+}
+
+fn commands_path(
+    application_id: Id<ApplicationMarker>,
+    guild_id: Option<Id<GuildMarker>>,
+) -> String {
+    match guild_id {
+        Some(guild_id) => format!("/applications/{application_id}/guilds/{guild_id}/commands"),
+        None => format!("/applications/{application_id}/commands"),
+    }
+}
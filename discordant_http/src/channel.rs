@@ -0,0 +1,172 @@
+use discordant_types::{
+    Channel, ChannelMarker, ChannelUpdate, GenericMarker, Id, Message, MessageCreate,
+    MessageMarker, MessageUpdate, Overwrite,
+};
+use serde::Serialize;
+
+use crate::{error::HttpError, Client, Request};
+
+/// Which page of a channel's messages to fetch. Only one of `around`, `before` and `after` is
+/// used by Discord.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GetMessages {
+    pub around: Option<Id<MessageMarker>>,
+    pub before: Option<Id<MessageMarker>>,
+    pub after: Option<Id<MessageMarker>>,
+    /// Between 1 and 100. Discord defaults to 50.
+    pub limit: Option<u8>,
+}
+
+impl GetMessages {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn around(mut self, value: Id<MessageMarker>) -> Self {
+        self.around = Some(value);
+        self
+    }
+
+    pub fn before(mut self, value: Id<MessageMarker>) -> Self {
+        self.before = Some(value);
+        self
+    }
+
+    pub fn after(mut self, value: Id<MessageMarker>) -> Self {
+        self.after = Some(value);
+        self
+    }
+
+    pub fn limit(mut self, value: u8) -> Self {
+        self.limit = Some(value);
+        self
+    }
+}
+
+impl Client {
+    pub async fn get_channel(&self, channel_id: Id<ChannelMarker>) -> Result<Channel, HttpError> {
+        self.request(Request::get(format!("/channels/{channel_id}")))
+            .await
+    }
+
+    pub async fn modify_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        update: &ChannelUpdate,
+    ) -> Result<Channel, HttpError> {
+        self.request(Request::patch(format!("/channels/{channel_id}")).json(update)?)
+            .await
+    }
+
+    pub async fn delete_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<Channel, HttpError> {
+        self.request(Request::delete(format!("/channels/{channel_id}")))
+            .await
+    }
+
+    /// Creates or replaces the overwrite for `overwrite.id`.
+    pub async fn edit_channel_permissions(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        overwrite: &Overwrite,
+    ) -> Result<(), HttpError> {
+        let path = format!("/channels/{channel_id}/permissions/{}", overwrite.id);
+
+        self.request_empty(Request::put(path).json(overwrite)?)
+            .await
+    }
+
+    pub async fn delete_channel_permission(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        overwrite_id: Id<GenericMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/channels/{channel_id}/permissions/{overwrite_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    pub async fn trigger_typing(&self, channel_id: Id<ChannelMarker>) -> Result<(), HttpError> {
+        self.request_empty(Request::post(format!("/channels/{channel_id}/typing")))
+            .await
+    }
+
+    pub async fn get_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<Message, HttpError> {
+        self.request(Request::get(format!(
+            "/channels/{channel_id}/messages/{message_id}"
+        )))
+        .await
+    }
+
+    pub async fn get_messages(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        query: GetMessages,
+    ) -> Result<Vec<Message>, HttpError> {
+        let request = Request::get(format!("/channels/{channel_id}/messages"))
+            .query("around", query.around)
+            .query("before", query.before)
+            .query("after", query.after)
+            .query("limit", query.limit);
+
+        self.request(request).await
+    }
+
+    pub async fn create_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message: &MessageCreate,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/channels/{channel_id}/messages");
+
+        self.request(Request::post(path).json(message)?).await
+    }
+
+    pub async fn edit_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+        update: &MessageUpdate,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/channels/{channel_id}/messages/{message_id}");
+
+        self.request(Request::patch(path).json(update)?).await
+    }
+
+    pub async fn delete_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/channels/{channel_id}/messages/{message_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    /// Deletes between 2 and 100 messages, none older than two weeks.
+    pub async fn bulk_delete_messages(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &[Id<MessageMarker>],
+    ) -> Result<(), HttpError> {
+        #[derive(Serialize)]
+        struct BulkDelete<'a> {
+            messages: &'a [Id<MessageMarker>],
+        }
+
+        let path = format!("/channels/{channel_id}/messages/bulk-delete");
+        let body = BulkDelete {
+            messages: message_ids,
+        };
+
+        self.request_empty(Request::post(path).json(&body)?).await
+    }
+}
@@ -0,0 +1,75 @@
+use std::{error::Error, fmt::Display};
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub enum HttpError {
+    Request(reqwest::Error),
+    /// Discord answered with an unsuccessful status. `error` holds the body, when it was one of
+    /// Discord's JSON errors.
+    Status {
+        status: StatusCode,
+        error: Option<ApiError>,
+    },
+    Serialize(serde_json::Error),
+    Deserialize(serde_json::Error),
+}
+
+impl HttpError {
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            HttpError::Request(e) => e.status(),
+            HttpError::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::Request(e) => write!(f, "request failed: {e}"),
+            HttpError::Status {
+                status,
+                error: Some(error),
+            } => write!(f, "Discord returned {status}: {error}"),
+            HttpError::Status { status, error: _ } => write!(f, "Discord returned {status}"),
+            HttpError::Serialize(e) => write!(f, "could not serialize request body: {e}"),
+            HttpError::Deserialize(e) => write!(f, "could not deserialize response body: {e}"),
+        }
+    }
+}
+
+impl Error for HttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HttpError::Request(e) => Some(e),
+            HttpError::Serialize(e) | HttpError::Deserialize(e) => Some(e),
+            HttpError::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(value: reqwest::Error) -> Self {
+        HttpError::Request(value)
+    }
+}
+
+/// The JSON error body Discord sends with unsuccessful responses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiError {
+    /// One of Discord's JSON error codes, such as `10008` for an unknown message.
+    pub code: u64,
+    pub message: String,
+    /// Per-field validation errors, nested the same way as the request body.
+    #[serde(default)]
+    pub errors: Option<serde_json::Value>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
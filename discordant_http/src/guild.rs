@@ -0,0 +1,113 @@
+use discordant_types::{
+    GuildMarker, GuildMember, GuildMemberUpdate, Id, Role, RoleMarker, RoleUpdate, UserMarker,
+};
+
+use crate::{error::HttpError, Client, Request};
+
+impl Client {
+    pub async fn get_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<GuildMember, HttpError> {
+        self.request(Request::get(format!(
+            "/guilds/{guild_id}/members/{user_id}"
+        )))
+        .await
+    }
+
+    /// Lists members in order of their user ids, starting after `after`. `limit` is between 1 and
+    /// 1000, and Discord defaults to 1.
+    pub async fn list_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+        limit: Option<u16>,
+        after: Option<Id<UserMarker>>,
+    ) -> Result<Vec<GuildMember>, HttpError> {
+        let request = Request::get(format!("/guilds/{guild_id}/members"))
+            .query("limit", limit)
+            .query("after", after);
+
+        self.request(request).await
+    }
+
+    pub async fn modify_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        update: &GuildMemberUpdate,
+    ) -> Result<GuildMember, HttpError> {
+        let path = format!("/guilds/{guild_id}/members/{user_id}");
+
+        self.request(Request::patch(path).json(update)?).await
+    }
+
+    /// Kicks a member from the guild.
+    pub async fn remove_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/guilds/{guild_id}/members/{user_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    pub async fn add_member_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/guilds/{guild_id}/members/{user_id}/roles/{role_id}");
+
+        self.request_empty(Request::put(path)).await
+    }
+
+    pub async fn remove_member_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/guilds/{guild_id}/members/{user_id}/roles/{role_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    pub async fn get_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Role>, HttpError> {
+        self.request(Request::get(format!("/guilds/{guild_id}/roles")))
+            .await
+    }
+
+    pub async fn create_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role: &RoleUpdate,
+    ) -> Result<Role, HttpError> {
+        let path = format!("/guilds/{guild_id}/roles");
+
+        self.request(Request::post(path).json(role)?).await
+    }
+
+    pub async fn modify_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+        update: &RoleUpdate,
+    ) -> Result<Role, HttpError> {
+        let path = format!("/guilds/{guild_id}/roles/{role_id}");
+
+        self.request(Request::patch(path).json(update)?).await
+    }
+
+    pub async fn delete_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/guilds/{guild_id}/roles/{role_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+}
@@ -0,0 +1,100 @@
+use discordant_types::{
+    ApplicationMarker, Id, InteractionCallbackData, InteractionMarker, InteractionResponse,
+    Message, MessageMarker, MessageUpdate,
+};
+
+use crate::{error::HttpError, Client, Request};
+
+/// Interaction tokens are valid for 15 minutes, and the follow-up endpoints are not subject to the
+/// bot's global rate limit.
+impl Client {
+    /// Responds to an interaction outside of the request Discord sent it in, such as after
+    /// deferring.
+    pub async fn create_interaction_response(
+        &self,
+        interaction_id: Id<InteractionMarker>,
+        token: &str,
+        response: &InteractionResponse,
+    ) -> Result<(), HttpError> {
+        let path = format!("/interactions/{interaction_id}/{token}/callback");
+
+        self.request_empty(Request::post(path).json(response)?)
+            .await
+    }
+
+    pub async fn get_original_response(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/@original");
+
+        self.request(Request::get(path)).await
+    }
+
+    pub async fn edit_original_response(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+        update: &MessageUpdate,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/@original");
+
+        self.request(Request::patch(path).json(update)?).await
+    }
+
+    pub async fn delete_original_response(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+    ) -> Result<(), HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/@original");
+
+        self.request_empty(Request::delete(path)).await
+    }
+
+    pub async fn create_followup(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+        message: &InteractionCallbackData,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}");
+
+        self.request(Request::post(path).json(message)?).await
+    }
+
+    pub async fn get_followup(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+        message_id: Id<MessageMarker>,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/{message_id}");
+
+        self.request(Request::get(path)).await
+    }
+
+    pub async fn edit_followup(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+        message_id: Id<MessageMarker>,
+        update: &MessageUpdate,
+    ) -> Result<Message, HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/{message_id}");
+
+        self.request(Request::patch(path).json(update)?).await
+    }
+
+    pub async fn delete_followup(
+        &self,
+        application_id: Id<ApplicationMarker>,
+        token: &str,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), HttpError> {
+        let path = format!("/webhooks/{application_id}/{token}/messages/{message_id}");
+
+        self.request_empty(Request::delete(path)).await
+    }
+}
@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use reqwest::{header, Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::{debug, warn};
+
+mod application_command;
+mod channel;
+pub mod error;
+mod guild;
+mod interaction;
+pub mod ratelimit;
+
+pub use channel::GetMessages;
+use error::{ApiError, HttpError};
+use ratelimit::{RateLimitHeaders, Ratelimiter, Route, TokioClock};
+
+pub const API_BASE_URL: &str = "https://discord.com/api/v10";
+
+const USER_AGENT: &str = concat!(
+    "DiscordBot (https://github.com/devnought/discordant, ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+/// How many times a request is retried after being rate limited.
+const MAX_RETRIES: usize = 3;
+
+/// A client for Discord's HTTP API, authenticated as a bot.
+///
+/// Cheap to clone; clones share a connection pool and rate limits.
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    authorization: String,
+    base_url: String,
+    ratelimiter: Option<Ratelimiter>,
+}
+
+impl Client {
+    /// `token` is the bot's token, with or without the `Bot ` prefix.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let token = token.into();
+        let authorization = match token.starts_with("Bot ") {
+            true => token,
+            false => format!("Bot {token}"),
+        };
+
+        Self {
+            http: reqwest::Client::new(),
+            authorization,
+            base_url: API_BASE_URL.to_string(),
+            ratelimiter: Some(Ratelimiter::new(TokioClock)),
+        }
+    }
+
+    /// Sends requests somewhere other than `API_BASE_URL`, such as a local mock server.
+    pub fn base_url<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut value = value.into();
+
+        while value.ends_with('/') {
+            value.pop();
+        }
+
+        self.base_url = value;
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = value;
+        self
+    }
+
+    /// Replaces the default in-process ratelimiter, or with `None` sends requests as soon as
+    /// they are made.
+    pub fn ratelimiter(mut self, value: Option<Ratelimiter>) -> Self {
+        self.ratelimiter = value;
+        self
+    }
+
+    pub(crate) async fn request<T>(&self, request: Request) -> Result<T, HttpError>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self.send(request).await?.bytes().await?;
+
+        serde_json::from_slice(&bytes).map_err(HttpError::Deserialize)
+    }
+
+    pub(crate) async fn request_empty(&self, request: Request) -> Result<(), HttpError> {
+        self.send(request).await.map(|_| ())
+    }
+
+    async fn send(&self, request: Request) -> Result<reqwest::Response, HttpError> {
+        let route = Route::new(&request.method, &request.path);
+        let mut retries = 0;
+
+        loop {
+            if let Some(ratelimiter) = &self.ratelimiter {
+                ratelimiter.acquire(&route).await;
+            }
+
+            debug!("{}", route.log_key());
+
+            let mut builder = self
+                .http
+                .request(
+                    request.method.clone(),
+                    format!("{}{}", self.base_url, request.path),
+                )
+                .header(header::AUTHORIZATION, &self.authorization)
+                .header(header::USER_AGENT, USER_AGENT)
+                .query(&request.query);
+
+            if let Some(body) = &request.body {
+                builder = builder
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(body.clone());
+            }
+
+            let response = match builder.send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    // Lets the next request on the route through
+                    self.update_limits(&route, &RateLimitHeaders::default())
+                        .await;
+
+                    return Err(e.into());
+                }
+            };
+
+            let status = response.status();
+            let mut limits = RateLimitHeaders::from_headers(response.headers());
+
+            if status.is_success() {
+                self.update_limits(&route, &limits).await;
+
+                return Ok(response);
+            }
+
+            let body = response.bytes().await;
+            let limited = status == StatusCode::TOO_MANY_REQUESTS;
+
+            // The body's `retry_after` is more precise than the header's whole seconds
+            if let Some(body) = body.as_ref().ok().filter(|_| limited) {
+                if let Ok(limited) = serde_json::from_slice::<RateLimited>(body) {
+                    limits.retry_after = Duration::try_from_secs_f64(limited.retry_after)
+                        .ok()
+                        .or(limits.retry_after);
+                    limits.global |= limited.global;
+                }
+            }
+
+            self.update_limits(&route, &limits).await;
+
+            let body = body?;
+
+            if limited && self.ratelimiter.is_some() && retries < MAX_RETRIES {
+                retries += 1;
+                warn!(
+                    "rate limited on {}, retrying after {:?}",
+                    route.log_key(),
+                    limits.retry_after
+                );
+                continue;
+            }
+
+            return Err(HttpError::Status {
+                status,
+                error: serde_json::from_slice::<ApiError>(&body).ok(),
+            });
+        }
+    }
+
+    async fn update_limits(&self, route: &Route, limits: &RateLimitHeaders) {
+        if let Some(ratelimiter) = &self.ratelimiter {
+            ratelimiter.update(route, limits).await;
+        }
+    }
+}
+
+/// The body of a `429` response.
+#[derive(Deserialize)]
+struct RateLimited {
+    retry_after: f64,
+    #[serde(default)]
+    global: bool,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) struct Request {
+    method: Method,
+    path: String,
+    query: Vec<(&'static str, String)>,
+    body: Option<Vec<u8>>,
+}
+
+impl Request {
+    pub(crate) fn new(method: Method, path: String) -> Self {
+        Self {
+            method,
+            path,
+            query: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub(crate) fn get(path: String) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    pub(crate) fn post(path: String) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    pub(crate) fn put(path: String) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    pub(crate) fn patch(path: String) -> Self {
+        Self::new(Method::PATCH, path)
+    }
+
+    pub(crate) fn delete(path: String) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    pub(crate) fn query<T>(mut self, name: &'static str, value: Option<T>) -> Self
+    where
+        T: ToString,
+    {
+        if let Some(value) = value {
+            self.query.push((name, value.to_string()));
+        }
+
+        self
+    }
+
+    pub(crate) fn json<T>(mut self, body: &T) -> Result<Self, HttpError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.body = Some(serde_json::to_vec(body).map_err(HttpError::Serialize)?);
+        Ok(self)
+    }
+}
@@ -0,0 +1,598 @@
+//! Discord's rate limits, tracked per route bucket and globally.
+//!
+//! Besides `TokioClock`, nothing here depends on an async runtime: waiting goes through
+//! `Clock::sleep`, and the state lives behind `RatelimitStore`, so it can be shared between
+//! processes.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    future::{poll_fn, Future},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, SystemTime},
+};
+
+use http::{HeaderMap, Method};
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Discord's default limit on requests per second across all routes.
+pub const GLOBAL_LIMIT: u32 = 50;
+
+const GLOBAL_WINDOW: Duration = Duration::from_secs(1);
+
+/// How long other requests wait for the first response on a bucket Discord has not reported yet,
+/// in case it never comes.
+const PENDING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a bucket is kept after its window has reset without being used.
+const PRUNE_AFTER: Duration = Duration::from_secs(60);
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// Reads the system time and sleeps on tokio's timer, which the client already runs on.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A route with its ids replaced by placeholders, except the major parameters Discord limits
+/// separately: channel, guild, webhook and interaction ids, and their tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Route {
+    key: String,
+    log_key: String,
+    template: String,
+    major: String,
+    global: bool,
+}
+
+impl Route {
+    pub fn new(method: &Method, path: &str) -> Self {
+        let segments = path
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut key = format!("{method} ");
+        let mut log_key = key.clone();
+        let mut template = key.clone();
+        let mut major = String::new();
+
+        for (index, segment) in segments.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|i| segments[i]);
+            let is_token = index == 2 && matches!(segments[0], "webhooks" | "interactions");
+            let is_major = match previous {
+                Some("channels" | "guilds" | "webhooks" | "interactions") => index == 1,
+                // Keeps each interaction to its own bucket, so callbacks never wait on each other
+                _ => is_token,
+            };
+
+            let part = if is_major {
+                major.push('/');
+                major.push_str(segment);
+                segment
+            } else if is_token {
+                ":token"
+            } else if segment.bytes().all(|b| b.is_ascii_digit()) {
+                ":id"
+            } else {
+                segment
+            };
+
+            key.push('/');
+            key.push_str(part);
+            log_key.push('/');
+            log_key.push_str(if is_token { ":token" } else { part });
+            template.push('/');
+            template.push_str(match (is_major, is_token) {
+                (_, true) => ":token",
+                (true, false) => ":id",
+                (false, false) => part,
+            });
+        }
+
+        // Interaction responses and follow-ups do not count towards the global limit
+        let global = !matches!(segments.first(), Some(&"interactions" | &"webhooks"));
+
+        Self {
+            key,
+            log_key,
+            template,
+            major,
+            global,
+        }
+    }
+
+    /// Includes webhook tokens, so it must not be logged. Use `log_key` instead.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The key with tokens masked, safe to log.
+    pub fn log_key(&self) -> &str {
+        &self.log_key
+    }
+
+    /// The key with its major parameters masked too. Discord reports the same bucket hash for
+    /// every route with the same template.
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    pub fn major(&self) -> &str {
+        &self.major
+    }
+
+    /// Whether requests on this route count towards the global limit.
+    pub fn is_global(&self) -> bool {
+        self.global
+    }
+}
+
+/// The rate limit Discord reports on a response.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimitHeaders {
+    /// Identifies routes that share a limit, together with the route's major parameters.
+    pub bucket: Option<String>,
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    pub reset_after: Option<Duration>,
+    /// Set on a `429` that applies to every route.
+    pub global: bool,
+    /// Set on a `429`, for how long to wait before retrying.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitHeaders {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let seconds = |name: &str| {
+            header(name)
+                .and_then(|value| value.parse::<f64>().ok())
+                .and_then(|value| Duration::try_from_secs_f64(value).ok())
+        };
+
+        Self {
+            bucket: header("x-ratelimit-bucket").map(str::to_string),
+            limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_after: seconds("x-ratelimit-reset-after"),
+            global: header("x-ratelimit-global") == Some("true"),
+            retry_after: seconds("retry-after"),
+        }
+    }
+}
+
+/// Where rate limit state is kept.
+///
+/// Implement this over something like Redis to share limits between processes. `now` comes from
+/// the ratelimiter's `Clock`, so every process should use comparable clocks.
+pub trait RatelimitStore: Send + Sync {
+    /// Reserves a request on `route`, or returns how long to wait before trying again.
+    fn acquire<'a>(&'a self, route: &'a Route, now: SystemTime) -> BoxFuture<'a, Option<Duration>>;
+
+    /// Records the limits Discord reported for a request on `route`.
+    fn update<'a>(
+        &'a self,
+        route: &'a Route,
+        headers: &'a RateLimitHeaders,
+        now: SystemTime,
+    ) -> BoxFuture<'a, ()>;
+}
+
+/// Keeps rate limit state in this process.
+#[derive(Debug)]
+pub struct MemoryStore {
+    global_limit: u32,
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    /// Bucket hashes Discord reported, by route template. `None` for routes whose responses
+    /// carry no bucket, which are never held to one request in flight.
+    hashes: HashMap<String, Option<String>>,
+    buckets: HashMap<String, Bucket>,
+    global: Window,
+    global_until: Option<SystemTime>,
+    pruned_at: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: u64,
+    remaining: u64,
+    reset_at: SystemTime,
+    /// Assumed length of the next window, until a response reports it.
+    window: Duration,
+    /// Stands in for a bucket whose first request has not been answered yet.
+    pending: bool,
+}
+
+#[derive(Debug, Default)]
+struct Window {
+    started: Option<SystemTime>,
+    count: u32,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::with_global_limit(GLOBAL_LIMIT)
+    }
+
+    /// For bots Discord has granted more than `GLOBAL_LIMIT` requests per second.
+    pub fn with_global_limit(limit: u32) -> Self {
+        Self {
+            global_limit: limit,
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryState {
+    fn bucket_key(&self, route: &Route) -> String {
+        match self.hashes.get(route.template()) {
+            Some(Some(hash)) => format!("{hash}:{}", route.major()),
+            _ => route.key().to_string(),
+        }
+    }
+
+    /// Drops buckets that have not been used for a while, such as those of expired interaction
+    /// tokens.
+    fn prune(&mut self, now: SystemTime) {
+        if self
+            .pruned_at
+            .is_some_and(|pruned_at| now < pruned_at + PRUNE_AFTER)
+        {
+            return;
+        }
+
+        self.buckets
+            .retain(|_, bucket| now < bucket.reset_at + PRUNE_AFTER);
+        self.pruned_at = Some(now);
+    }
+
+    fn acquire(&mut self, route: &Route, now: SystemTime, global_limit: u32) -> Option<Duration> {
+        self.prune(now);
+
+        if let Some(until) = self.global_until {
+            match until.duration_since(now) {
+                Ok(wait) if !wait.is_zero() => return Some(wait),
+                _ => self.global_until = None,
+            }
+        }
+
+        let key = self.bucket_key(route);
+
+        if let Some(bucket) = self.buckets.get_mut(&key) {
+            if now >= bucket.reset_at {
+                bucket.remaining = bucket.limit;
+                bucket.reset_at = now + bucket.window;
+            }
+
+            if bucket.remaining == 0 {
+                return bucket.reset_at.duration_since(now).ok();
+            }
+        }
+
+        if route.is_global() {
+            let window = &mut self.global;
+            let started = match window.started {
+                Some(started) if now < started + GLOBAL_WINDOW => started,
+                _ => {
+                    window.count = 0;
+                    *window.started.insert(now)
+                }
+            };
+
+            if window.count >= global_limit {
+                return (started + GLOBAL_WINDOW).duration_since(now).ok();
+            }
+
+            window.count += 1;
+        }
+
+        match self.buckets.get_mut(&key) {
+            Some(bucket) => bucket.remaining -= 1,
+            None if self.hashes.get(route.template()) == Some(&None) => {}
+            // Until Discord says what the limit is, only let one request through
+            None => {
+                self.buckets.insert(
+                    key,
+                    Bucket {
+                        limit: 1,
+                        remaining: 0,
+                        reset_at: now + PENDING_TIMEOUT,
+                        window: PENDING_TIMEOUT,
+                        pending: true,
+                    },
+                );
+            }
+        }
+
+        None
+    }
+
+    fn update(&mut self, route: &Route, headers: &RateLimitHeaders, now: SystemTime) {
+        self.prune(now);
+
+        let previous = self.bucket_key(route);
+
+        if self.buckets.get(&previous).is_some_and(|b| b.pending) {
+            self.buckets.remove(&previous);
+        }
+
+        match &headers.bucket {
+            Some(hash) => {
+                self.hashes
+                    .insert(route.template().to_string(), Some(hash.clone()));
+            }
+            None if headers.limit.is_none() && headers.retry_after.is_none() => {
+                self.hashes
+                    .entry(route.template().to_string())
+                    .or_insert(None);
+            }
+            None => {}
+        }
+
+        if headers.global {
+            let wait = headers.retry_after.unwrap_or(GLOBAL_WINDOW);
+            self.global_until = Some(now + wait);
+            return;
+        }
+
+        let key = self.bucket_key(route);
+
+        if let Some(retry_after) = headers.retry_after {
+            let bucket = self.buckets.entry(key).or_insert(Bucket {
+                limit: headers.limit.unwrap_or(1).max(1),
+                remaining: 0,
+                reset_at: now,
+                window: retry_after,
+                pending: false,
+            });
+
+            bucket.remaining = 0;
+            bucket.reset_at = now + retry_after;
+            return;
+        }
+
+        if let (Some(limit), Some(remaining), Some(reset_after)) =
+            (headers.limit, headers.remaining, headers.reset_after)
+        {
+            let reset_at = now + reset_after;
+
+            match self.buckets.get_mut(&key) {
+                // Responses can arrive out of order, so only ever lower what is left in a window
+                Some(bucket) if bucket.reset_at >= reset_at && now < bucket.reset_at => {
+                    bucket.limit = limit.max(1);
+                    bucket.remaining = bucket.remaining.min(remaining);
+                }
+                _ => {
+                    self.buckets.insert(
+                        key,
+                        Bucket {
+                            limit: limit.max(1),
+                            remaining,
+                            reset_at,
+                            window: reset_after,
+                            pending: false,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl RatelimitStore for MemoryStore {
+    fn acquire<'a>(&'a self, route: &'a Route, now: SystemTime) -> BoxFuture<'a, Option<Duration>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let wait = state.acquire(route, now, self.global_limit);
+
+        Box::pin(std::future::ready(wait))
+    }
+
+    fn update<'a>(
+        &'a self,
+        route: &'a Route,
+        headers: &'a RateLimitHeaders,
+        now: SystemTime,
+    ) -> BoxFuture<'a, ()> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.update(route, headers, now);
+
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// Waits for a request's turn, and learns limits from the responses.
+///
+/// Requests on the same route wait in line, and only the first in line asks the store whether it
+/// can go. Clones share their lines.
+#[derive(Clone)]
+pub struct Ratelimiter {
+    clock: Arc<dyn Clock>,
+    store: Arc<dyn RatelimitStore>,
+    lines: Arc<Mutex<HashMap<String, Line>>>,
+}
+
+#[derive(Default)]
+struct Line {
+    next_id: u64,
+    waiters: VecDeque<Waiter>,
+    /// Set when a response on the route arrives, so the first in line checks the store again.
+    updated: bool,
+}
+
+struct Waiter {
+    id: u64,
+    waker: Option<Waker>,
+}
+
+/// A request's place in its route's line, given up when dropped.
+struct Place<'a> {
+    lines: &'a Mutex<HashMap<String, Line>>,
+    key: &'a str,
+    id: u64,
+}
+
+impl Ratelimiter {
+    pub fn new<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self {
+            clock: Arc::new(clock),
+            store: Arc::new(MemoryStore::new()),
+            lines: Arc::default(),
+        }
+    }
+
+    pub fn store<S>(mut self, value: S) -> Self
+    where
+        S: RatelimitStore + 'static,
+    {
+        self.store = Arc::new(value);
+        self
+    }
+
+    /// Waits until a request on `route` can be sent.
+    pub async fn acquire(&self, route: &Route) {
+        let place = Place::join(&self.lines, route.key());
+        poll_fn(|cx| place.poll_turn(cx)).await;
+
+        loop {
+            place.with_line(|line| line.updated = false);
+
+            let Some(wait) = self.store.acquire(route, self.clock.now()).await else {
+                break;
+            };
+
+            // Woken early when a response on this route arrives
+            let mut sleep = self.clock.sleep(wait);
+            poll_fn(|cx| match sleep.as_mut().poll(cx) {
+                Poll::Ready(()) => Poll::Ready(()),
+                Poll::Pending => place.poll_updated(cx),
+            })
+            .await;
+        }
+    }
+
+    pub async fn update(&self, route: &Route, headers: &RateLimitHeaders) {
+        self.store.update(route, headers, self.clock.now()).await;
+
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(line) = lines.get_mut(route.key()) {
+            line.updated = true;
+            line.wake_first();
+        }
+    }
+}
+
+impl std::fmt::Debug for Ratelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ratelimiter").finish_non_exhaustive()
+    }
+}
+
+impl Line {
+    fn wake_first(&mut self) {
+        if let Some(waker) = self.waiters.front_mut().and_then(|w| w.waker.take()) {
+            waker.wake();
+        }
+    }
+}
+
+impl<'a> Place<'a> {
+    fn join(lines: &'a Mutex<HashMap<String, Line>>, key: &'a str) -> Self {
+        let mut guard = lines.lock().unwrap_or_else(|e| e.into_inner());
+        let line = guard.entry(key.to_string()).or_default();
+        let id = line.next_id;
+
+        line.next_id += 1;
+        line.waiters.push_back(Waiter { id, waker: None });
+
+        Self { lines, key, id }
+    }
+
+    fn with_line<T>(&self, f: impl FnOnce(&mut Line) -> T) -> T {
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        let line = lines
+            .get_mut(self.key)
+            .expect("line is kept while it has waiters");
+
+        f(line)
+    }
+
+    fn poll_turn(&self, cx: &mut Context<'_>) -> Poll<()> {
+        self.with_line(|line| {
+            let first = line.waiters.front().map(|w| w.id);
+
+            match line.waiters.iter_mut().find(|w| w.id == self.id) {
+                Some(_) if first == Some(self.id) => Poll::Ready(()),
+                Some(waiter) => {
+                    waiter.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+                None => unreachable!("waiters stay in line until dropped"),
+            }
+        })
+    }
+
+    fn poll_updated(&self, cx: &mut Context<'_>) -> Poll<()> {
+        self.with_line(|line| {
+            if line.updated {
+                return Poll::Ready(());
+            }
+
+            if let Some(waiter) = line.waiters.front_mut() {
+                waiter.waker = Some(cx.waker().clone());
+            }
+
+            Poll::Pending
+        })
+    }
+}
+
+impl Drop for Place<'_> {
+    fn drop(&mut self) {
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+
+        let Some(line) = lines.get_mut(self.key) else {
+            return;
+        };
+
+        line.waiters.retain(|w| w.id != self.id);
+
+        match line.waiters.is_empty() {
+            true => {
+                lines.remove(self.key);
+            }
+            false => line.wake_first(),
+        }
+    }
+}
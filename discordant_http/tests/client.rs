@@ -0,0 +1,244 @@
+use discordant_http::{error::HttpError, Client, GetMessages};
+use discordant_types::{
+    ApplicationCommand, GuildMemberUpdate, Id, InteractionCallbackData, InteractionCallbackType,
+    InteractionResponse, MessageCreate,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const MESSAGE: &str =
+    include_str!("../../discordant_types/tests/payloads/message/reply_with_embed.json");
+
+async fn client() -> (MockServer, Client) {
+    let server = MockServer::start().await;
+    let client = Client::new("TOKEN").base_url(format!("{}/", server.uri()));
+
+    (server, client)
+}
+
+fn message() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(MESSAGE, "application/json")
+}
+
+#[tokio::test]
+async fn create_message() {
+    let (server, client) = client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/channels/772908445358620702/messages"))
+        .and(header("authorization", "Bot TOKEN"))
+        .and(body_json(json!({ "content": "here it is" })))
+        .respond_with(message())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let message = client
+        .create_message(
+            Id::new(772908445358620702),
+            &MessageCreate::new().content("here it is"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(message.id, Id::new(1164660124065222727));
+}
+
+#[tokio::test]
+async fn get_messages_sends_query() {
+    let (server, client) = client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/channels/772908445358620702/messages"))
+        .and(query_param("before", "1164660124065222727"))
+        .and(query_param("limit", "10"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(format!("[{MESSAGE}]"), "application/json"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let messages = client
+        .get_messages(
+            Id::new(772908445358620702),
+            GetMessages::new()
+                .before(Id::new(1164660124065222727))
+                .limit(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(messages.len(), 1);
+}
+
+#[tokio::test]
+async fn modify_member() {
+    let (server, client) = client().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/guilds/1/members/2"))
+        .and(body_json(json!({ "nick": "new" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "user": { "id": "2", "username": "user", "discriminator": "0", "avatar": null },
+            "nick": "new",
+            "roles": ["3"],
+            "joined_at": "2021-02-12T18:25:07.972000+00:00",
+            "deaf": false,
+            "mute": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let update = GuildMemberUpdate {
+        nick: Some("new".to_string()),
+        ..Default::default()
+    };
+
+    let member = client
+        .modify_member(Id::new(1), Id::new(2), &update)
+        .await
+        .unwrap();
+
+    assert_eq!(member.nick.as_deref(), Some("new"));
+    assert!(member.roles.contains(&Id::new(3)));
+}
+
+#[tokio::test]
+async fn interaction_response_and_followup() {
+    let (server, client) = client().await;
+
+    Mock::given(method("POST"))
+        .and(path("/interactions/5/TOKEN/callback"))
+        .and(body_json(json!({ "type": 5 })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/webhooks/6/TOKEN"))
+        .and(body_json(json!({ "content": "done" })))
+        .respond_with(message())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let deferred = InteractionResponse::new()
+        .response_type(InteractionCallbackType::DeferredChannelMessageWithSource);
+
+    client
+        .create_interaction_response(Id::new(5), "TOKEN", &deferred)
+        .await
+        .unwrap();
+
+    client
+        .create_followup(
+            Id::new(6),
+            "TOKEN",
+            &InteractionCallbackData::new().content("done"),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_guild_commands() {
+    let (server, client) = client().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/applications/6/guilds/1/commands"))
+        .and(body_json(
+            json!([{ "name": "ping", "description": "Pong" }]),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": "7",
+            "application_id": "6",
+            "guild_id": "1",
+            "version": "8",
+            "type": 1,
+            "name": "ping",
+            "description": "Pong"
+        }])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let commands = client
+        .set_commands(
+            Id::new(6),
+            Some(Id::new(1)),
+            &[ApplicationCommand::new("ping").description("Pong")],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(commands[0].id, Some(Id::new(7)));
+}
+
+#[tokio::test]
+async fn error_body_is_parsed() {
+    let (server, client) = client().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/channels/1/messages/2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "code": 10008,
+            "message": "Unknown Message"
+        })))
+        .mount(&server)
+        .await;
+
+    let error = client
+        .delete_message(Id::new(1), Id::new(2))
+        .await
+        .unwrap_err();
+
+    match error {
+        HttpError::Status {
+            status,
+            error: Some(error),
+        } => {
+            assert_eq!(status, 404);
+            assert_eq!(error.code, 10008);
+        }
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn retries_after_429() {
+    let (server, client) = client().await;
+
+    Mock::given(method("GET"))
+        .and(path("/channels/1/messages/2"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("x-ratelimit-bucket", "abcd")
+                .insert_header("retry-after", "1")
+                .set_body_json(json!({
+                    "message": "You are being rate limited.",
+                    "retry_after": 0.01,
+                    "global": false
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/channels/1/messages/2"))
+        .respond_with(message())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let message = client.get_message(Id::new(1), Id::new(2)).await.unwrap();
+
+    assert_eq!(message.id, Id::new(1164660124065222727));
+}
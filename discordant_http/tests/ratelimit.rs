@@ -0,0 +1,363 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use discordant_http::ratelimit::{
+    BoxFuture, Clock, MemoryStore, RateLimitHeaders, Ratelimiter, Route,
+};
+use futures_util::FutureExt;
+use http::{HeaderMap, HeaderValue, Method};
+
+/// Sleeping moves time forward instead of waiting.
+#[derive(Clone)]
+struct ManualClock(Arc<Mutex<SystemTime>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Arc::new(Mutex::new(SystemTime::UNIX_EPOCH)))
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.now().duration_since(SystemTime::UNIX_EPOCH).unwrap()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        *self.0.lock().unwrap() += duration;
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// Never moves, so waiters only go when a response lets them.
+struct FrozenClock;
+
+impl Clock for FrozenClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH
+    }
+
+    fn sleep(&self, _: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(std::future::pending())
+    }
+}
+
+/// Lets every spawned task run until it blocks.
+async fn settle() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}
+
+fn limits(bucket: &str, limit: u64, remaining: u64, reset_after: f64) -> RateLimitHeaders {
+    RateLimitHeaders {
+        bucket: Some(bucket.to_string()),
+        limit: Some(limit),
+        remaining: Some(remaining),
+        reset_after: Some(Duration::from_secs_f64(reset_after)),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn route_keeps_major_parameters() {
+    let route = Route::new(&Method::DELETE, "/channels/1/messages/2");
+    assert_eq!(route.key(), "DELETE /channels/1/messages/:id");
+    assert_eq!(route.major(), "/1");
+    assert!(route.is_global());
+
+    let route = Route::new(&Method::PATCH, "/guilds/3/members/4");
+    assert_eq!(route.key(), "PATCH /guilds/3/members/:id");
+
+    let route = Route::new(&Method::PATCH, "/webhooks/5/TOKEN/messages/@original");
+    assert_eq!(route.key(), "PATCH /webhooks/5/TOKEN/messages/@original");
+    assert_eq!(route.major(), "/5/TOKEN");
+    assert_eq!(
+        route.template(),
+        "PATCH /webhooks/:id/:token/messages/@original"
+    );
+    assert!(!route.is_global());
+
+    let route = Route::new(&Method::POST, "/interactions/6/TOKEN/callback");
+    assert_eq!(route.key(), "POST /interactions/6/TOKEN/callback");
+    assert_eq!(route.major(), "/6/TOKEN");
+    assert_eq!(route.template(), "POST /interactions/:id/:token/callback");
+    assert!(!route.is_global());
+}
+
+#[test]
+fn log_key_masks_tokens() {
+    let route = Route::new(&Method::PATCH, "/webhooks/5/TOKEN/messages/@original");
+    assert_eq!(
+        route.log_key(),
+        "PATCH /webhooks/5/:token/messages/@original"
+    );
+
+    let route = Route::new(&Method::POST, "/interactions/6/TOKEN/callback");
+    assert_eq!(route.log_key(), "POST /interactions/6/:token/callback");
+
+    let route = Route::new(&Method::DELETE, "/channels/1/messages/2");
+    assert_eq!(route.log_key(), route.key());
+}
+
+#[test]
+fn headers_are_parsed() {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-ratelimit-bucket", HeaderValue::from_static("abcd1234"));
+    headers.insert("x-ratelimit-limit", HeaderValue::from_static("5"));
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+    headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("64.57"));
+    headers.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+    headers.insert("retry-after", HeaderValue::from_static("65"));
+
+    let limits = RateLimitHeaders::from_headers(&headers);
+
+    assert_eq!(limits.bucket.as_deref(), Some("abcd1234"));
+    assert_eq!(limits.limit, Some(5));
+    assert_eq!(limits.remaining, Some(0));
+    assert_eq!(limits.reset_after, Some(Duration::from_secs_f64(64.57)));
+    assert!(limits.global);
+    assert_eq!(limits.retry_after, Some(Duration::from_secs(65)));
+}
+
+#[tokio::test]
+async fn waits_for_exhausted_bucket() {
+    let clock = ManualClock::new();
+    let ratelimiter = Ratelimiter::new(clock.clone());
+    let route = Route::new(&Method::POST, "/channels/1/messages");
+
+    ratelimiter.acquire(&route).await;
+    ratelimiter.update(&route, &limits("a", 2, 1, 2.0)).await;
+    ratelimiter.acquire(&route).await;
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    ratelimiter.acquire(&route).await;
+    assert_eq!(clock.elapsed(), Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn buckets_are_split_by_major_parameter() {
+    let clock = ManualClock::new();
+    let ratelimiter = Ratelimiter::new(clock.clone());
+    let first = Route::new(&Method::POST, "/channels/1/messages");
+    let second = Route::new(&Method::POST, "/channels/2/messages");
+
+    ratelimiter.update(&first, &limits("a", 5, 0, 3.0)).await;
+    ratelimiter.acquire(&second).await;
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    ratelimiter.acquire(&first).await;
+    assert_eq!(clock.elapsed(), Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn routes_share_a_bucket_hash() {
+    let clock = ManualClock::new();
+    let ratelimiter = Ratelimiter::new(clock.clone());
+    let edit = Route::new(&Method::PATCH, "/channels/1/messages/2");
+    let create = Route::new(&Method::POST, "/channels/1/messages");
+
+    ratelimiter
+        .update(&create, &limits("shared", 5, 4, 5.0))
+        .await;
+    ratelimiter
+        .update(&edit, &limits("shared", 5, 0, 5.0))
+        .await;
+
+    ratelimiter.acquire(&create).await;
+    assert_eq!(clock.elapsed(), Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn global_limit_per_second() {
+    let clock = ManualClock::new();
+    let ratelimiter = Ratelimiter::new(clock.clone()).store(MemoryStore::with_global_limit(3));
+
+    for channel in 0..3 {
+        let route = Route::new(&Method::GET, &format!("/channels/{channel}"));
+        ratelimiter.acquire(&route).await;
+    }
+
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    // Interactions are exempt from the global limit
+    let interaction = Route::new(&Method::POST, "/interactions/1/TOKEN/callback");
+    ratelimiter.acquire(&interaction).await;
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    ratelimiter
+        .acquire(&Route::new(&Method::GET, "/channels/4"))
+        .await;
+    assert_eq!(clock.elapsed(), Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn global_429_blocks_every_route() {
+    let clock = ManualClock::new();
+    let ratelimiter = Ratelimiter::new(clock.clone());
+    let route = Route::new(&Method::GET, "/channels/1");
+    let limited = RateLimitHeaders {
+        global: true,
+        retry_after: Some(Duration::from_millis(1500)),
+        ..Default::default()
+    };
+
+    ratelimiter.update(&route, &limited).await;
+    ratelimiter
+        .acquire(&Route::new(&Method::GET, "/guilds/2/roles"))
+        .await;
+
+    assert_eq!(clock.elapsed(), Duration::from_millis(1500));
+}
+
+#[tokio::test]
+async fn store_is_shared_between_ratelimiters() {
+    let clock = ManualClock::new();
+    let store = Arc::new(MemoryStore::new());
+    let first = Ratelimiter::new(clock.clone()).store(Shared(store.clone()));
+    let second = Ratelimiter::new(clock.clone()).store(Shared(store));
+    let route = Route::new(&Method::POST, "/channels/1/messages");
+
+    first.update(&route, &limits("a", 5, 0, 4.0)).await;
+    second.acquire(&route).await;
+
+    assert_eq!(clock.elapsed(), Duration::from_secs(4));
+}
+
+#[tokio::test]
+async fn unused_buckets_are_dropped() {
+    let clock = ManualClock::new();
+    let store = Arc::new(MemoryStore::new());
+    let ratelimiter = Ratelimiter::new(clock.clone()).store(Shared(store.clone()));
+    let route = Route::new(&Method::POST, "/webhooks/1/TOKEN");
+
+    ratelimiter.update(&route, &limits("a", 5, 0, 1.0)).await;
+    assert!(format!("{store:?}").contains("TOKEN"));
+
+    clock.sleep(Duration::from_secs(120)).await;
+    ratelimiter
+        .acquire(&Route::new(&Method::GET, "/channels/1"))
+        .await;
+    assert!(!format!("{store:?}").contains("TOKEN"));
+}
+
+#[tokio::test]
+async fn requests_queue_behind_the_first_on_a_new_bucket() {
+    let ratelimiter = Ratelimiter::new(FrozenClock);
+    let route = Route::new(&Method::POST, "/channels/1/messages");
+    let sent = Arc::new(Mutex::new(Vec::new()));
+
+    ratelimiter.acquire(&route).await;
+
+    let tasks = (0..3)
+        .map(|i| {
+            let ratelimiter = ratelimiter.clone();
+            let route = route.clone();
+            let sent = sent.clone();
+
+            tokio::spawn(async move {
+                ratelimiter.acquire(&route).await;
+                sent.lock().unwrap().push(i);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    settle().await;
+    assert!(sent.lock().unwrap().is_empty());
+
+    ratelimiter.update(&route, &limits("a", 5, 1, 1.0)).await;
+    settle().await;
+    assert_eq!(*sent.lock().unwrap(), [0]);
+
+    ratelimiter.update(&route, &limits("a", 5, 2, 2.0)).await;
+
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    assert_eq!(*sent.lock().unwrap(), [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn dropped_waiters_leave_the_line() {
+    let ratelimiter = Ratelimiter::new(FrozenClock);
+    let route = Route::new(&Method::POST, "/channels/1/messages");
+
+    ratelimiter.acquire(&route).await;
+
+    let first = tokio::spawn({
+        let ratelimiter = ratelimiter.clone();
+        let route = route.clone();
+        async move { ratelimiter.acquire(&route).await }
+    });
+
+    settle().await;
+    first.abort();
+    settle().await;
+
+    ratelimiter.update(&route, &limits("a", 5, 5, 1.0)).await;
+    ratelimiter.acquire(&route).await;
+}
+
+#[tokio::test]
+async fn interactions_do_not_wait_on_each_other() {
+    let ratelimiter = Ratelimiter::new(FrozenClock);
+
+    // Neither callback has been answered, so a shared bucket would hold the second back
+    for interaction in 0..3 {
+        let route = Route::new(
+            &Method::POST,
+            &format!("/interactions/{interaction}/TOKEN{interaction}/callback"),
+        );
+
+        ratelimiter
+            .acquire(&route)
+            .now_or_never()
+            .expect("interactions have their own buckets");
+    }
+}
+
+#[tokio::test]
+async fn routes_without_buckets_are_not_held_back() {
+    let ratelimiter = Ratelimiter::new(FrozenClock);
+    let route = Route::new(&Method::GET, "/gateway");
+
+    ratelimiter.acquire(&route).await;
+    ratelimiter
+        .update(&route, &RateLimitHeaders::default())
+        .await;
+
+    for _ in 0..3 {
+        ratelimiter
+            .acquire(&route)
+            .now_or_never()
+            .expect("no bucket to wait on");
+    }
+
+    // Until a response reports one
+    ratelimiter.update(&route, &limits("a", 1, 0, 1.0)).await;
+    assert!(ratelimiter.acquire(&route).now_or_never().is_none());
+}
+
+/// Stands in for a store another process also uses.
+struct Shared(Arc<MemoryStore>);
+
+impl discordant_http::ratelimit::RatelimitStore for Shared {
+    fn acquire<'a>(&'a self, route: &'a Route, now: SystemTime) -> BoxFuture<'a, Option<Duration>> {
+        self.0.acquire(route, now)
+    }
+
+    fn update<'a>(
+        &'a self,
+        route: &'a Route,
+        headers: &'a RateLimitHeaders,
+        now: SystemTime,
+    ) -> BoxFuture<'a, ()> {
+        self.0.update(route, headers, now)
+    }
+}
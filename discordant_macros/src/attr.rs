@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parse_quote, spanned::Spanned, Attribute, Expr, ExprLit, ExprUnary,
+    Ident, Lit, LitInt, LitStr, Meta, MetaNameValue, Path, UnOp,
+};
+
+#[derive(Default)]
+pub struct OptionAttrs {
+    pub description: Option<LitStr>,
+    pub rename: Option<LitStr>,
+    pub min_value: Option<Expr>,
+    pub max_value: Option<Expr>,
+    pub min_length: Option<LitInt>,
+    pub max_length: Option<LitInt>,
+    pub choices: Vec<(LitStr, Expr)>,
+    pub channel_types: Vec<Ident>,
+    pub autocomplete: bool,
+}
+
+impl OptionAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self {
+            description: doc_comment(attrs),
+            ..Default::default()
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("command")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("description") {
+                    result.description = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename") {
+                    result.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("min_value") {
+                    result.min_value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max_value") {
+                    result.max_value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("min_length") {
+                    result.min_length = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max_length") {
+                    result.max_length = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("choice") {
+                    result.choices.push(parse_choice(&meta)?);
+                } else if meta.path.is_ident("channel_types") {
+                    meta.parse_nested_meta(|meta| {
+                        let ident = meta.path.require_ident()?;
+                        result.channel_types.push(ident.clone());
+                        Ok(())
+                    })?;
+                } else if meta.path.is_ident("autocomplete") {
+                    result.autocomplete = true;
+                } else {
+                    return Err(meta.error("unsupported command attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+fn parse_choice(meta: &ParseNestedMeta) -> syn::Result<(LitStr, Expr)> {
+    let mut name = None;
+    let mut value = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("value") {
+            value = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `name` or `value`"));
+        }
+
+        Ok(())
+    })?;
+
+    match (name, value) {
+        (Some(name), Some(value)) => Ok((name, value)),
+        _ => Err(meta.error("a choice needs both a `name` and a `value`")),
+    }
+}
+
+/// The path generated code reaches `discordant_types` through, from `#[command(crate = "...")]`
+/// on the type. Defaults to `::discordant_types`.
+pub fn crate_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    let mut result = parse_quote!(::discordant_types);
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("command")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                result = meta.value()?.parse::<LitStr>()?.parse()?;
+            } else {
+                return Err(meta.error("unsupported command attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+/// Collects `///` comments into a single line, for use as a description.
+pub fn doc_comment(attrs: &[Attribute]) -> Option<LitStr> {
+    let mut lines = Vec::new();
+    let mut span = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("doc")) {
+        if let Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }),
+            ..
+        }) = &attr.meta
+        {
+            lines.push(s.value().trim().to_string());
+            span.get_or_insert(s.span());
+        }
+    }
+
+    let text = lines
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    span.map(|span| LitStr::new(&text, span))
+}
+
+enum Number {
+    Integer,
+    Float,
+}
+
+fn number_kind(expr: &Expr) -> syn::Result<Number> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(_), ..
+        }) => Ok(Number::Integer),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(_), ..
+        }) => Ok(Number::Float),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => number_kind(expr),
+        _ => Err(syn::Error::new(expr.span(), "expected a number literal")),
+    }
+}
+
+pub fn min_max_value(expr: &Expr, krate: &Path) -> syn::Result<TokenStream> {
+    Ok(match number_kind(expr)? {
+        Number::Integer => quote!(#krate::MinMaxValue::Integer(#expr)),
+        Number::Float => quote!(#krate::MinMaxValue::Number(#expr)),
+    })
+}
+
+pub fn choice_value(expr: &Expr, krate: &Path) -> syn::Result<TokenStream> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(s), ..
+    }) = expr
+    {
+        return Ok(quote!(
+            #krate::ApplicationCommandOptionChoiceValue::String(
+                ::std::borrow::Cow::Borrowed(#s)
+            )
+        ));
+    }
+
+    Ok(match number_kind(expr)? {
+        Number::Integer => {
+            quote!(#krate::ApplicationCommandOptionChoiceValue::Integer(#expr))
+        }
+        Number::Float => {
+            quote!(#krate::ApplicationCommandOptionChoiceValue::Double(#expr))
+        }
+    })
+}
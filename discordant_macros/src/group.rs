@@ -0,0 +1,206 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Attribute, Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+use crate::{
+    attr::doc_comment,
+    options::{expand_fields, impl_lifetime, FieldsExpansion},
+};
+
+struct GroupAttrs {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+    group: bool,
+    krate: Option<Path>,
+}
+
+impl GroupAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self {
+            name: None,
+            description: doc_comment(attrs),
+            group: false,
+            krate: None,
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("command")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") || meta.path.is_ident("rename") {
+                    result.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("description") {
+                    result.description = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("group") {
+                    result.group = true;
+                } else if meta.path.is_ident("crate") {
+                    result.krate = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported command attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    fn name(&self, ident: &Ident) -> LitStr {
+        self.name
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&snake_case(&ident.to_string()), ident.span()))
+    }
+
+    fn description(&self, span: proc_macro2::Span) -> syn::Result<LitStr> {
+        self.description.clone().ok_or_else(|| {
+            syn::Error::new(
+                span,
+                "missing description, add a doc comment or #[command(description = \"...\")]",
+            )
+        })
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let lifetime = impl_lifetime(&input.generics)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let impl_generics = if input.generics.params.is_empty() {
+        quote!(<#lifetime>)
+    } else {
+        quote!(#impl_generics)
+    };
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "CommandGroup can only be derived for enums",
+            ))
+        }
+    };
+
+    let attrs = GroupAttrs::parse(&input.attrs)?;
+    let command_name = attrs.name(ident);
+    let command_description = attrs.description(ident.span())?;
+    let krate = attrs
+        .krate
+        .unwrap_or_else(|| syn::parse_quote!(::discordant_types));
+
+    let mut definitions = Vec::with_capacity(variants.len());
+    let mut arms = Vec::with_capacity(variants.len());
+
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let attrs = GroupAttrs::parse(&variant.attrs)?;
+        let name = attrs.name(variant_ident);
+        let description = attrs.description(variant.span())?;
+
+        let (option_type, options, arm) = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+
+                if attrs.group {
+                    (
+                        quote!(SubCommandGroup),
+                        quote!(<#ty as #krate::CommandGroup<#lifetime>>::command().options),
+                        quote! {
+                            [#name, rest @ ..] => ::std::result::Result::Ok(Self::#variant_ident(
+                                <#ty as #krate::CommandGroup<#lifetime>>::from_path(rest, options)?,
+                            ))
+                        },
+                    )
+                } else {
+                    (
+                        quote!(SubCommand),
+                        quote!(<#ty as #krate::CommandOptions<#lifetime>>::options()),
+                        quote! {
+                            [#name] => ::std::result::Result::Ok(Self::#variant_ident(
+                                <#ty as #krate::CommandOptions<#lifetime>>::from_options(options)?,
+                            ))
+                        },
+                    )
+                }
+            }
+            Fields::Unnamed(fields) => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "subcommand variants hold a single options struct",
+                ))
+            }
+            fields => {
+                if attrs.group {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "a subcommand group variant must hold a CommandGroup",
+                    ));
+                }
+
+                let FieldsExpansion { definitions, parse } =
+                    expand_fields(fields, &lifetime, &krate)?;
+
+                (
+                    quote!(SubCommand),
+                    definitions,
+                    quote!([#name] => ::std::result::Result::Ok(Self::#variant_ident #parse)),
+                )
+            }
+        };
+
+        definitions.push(quote! {
+            #krate::ApplicationCommandOption {
+                options: #options,
+                ..#krate::ApplicationCommandOption::new(#name)
+                    .application_command_option_type(
+                        #krate::ApplicationCommandOptionType::#option_type,
+                    )
+                    .description(#description)
+            }
+        });
+        arms.push(arm);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #krate::CommandGroup<#lifetime> for #ident #ty_generics
+        #where_clause
+        {
+            fn command() -> #krate::ApplicationCommand<'static> {
+                #krate::ApplicationCommand {
+                    options: ::std::vec![#(#definitions),*],
+                    ..#krate::ApplicationCommand::new(#command_name)
+                        .description(#command_description)
+                }
+            }
+
+            fn from_path(
+                path: &[&str],
+                options: &#krate::InteractionOptions<#lifetime>,
+            ) -> ::std::result::Result<Self, #krate::OptionError> {
+                match path {
+                    #(#arms,)*
+                    _ => ::std::result::Result::Err(
+                        #krate::OptionError::UnknownSubcommand(path.join(" ")),
+                    ),
+                }
+            }
+        }
+    })
+}
+
+fn snake_case(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 4);
+
+    for (i, c) in value.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
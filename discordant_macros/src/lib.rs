@@ -0,0 +1,50 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod group;
+mod options;
+
+/// Derives `discordant_types::CommandOptions` for a struct with named fields.
+///
+/// Every field becomes one command option named after the field. Fields are configured with
+/// `#[command(...)]`:
+///
+/// - `description = "..."`, falling back to the field's doc comment
+/// - `rename = "..."`
+/// - `min_value = ..`, `max_value = ..`, `min_length = ..`, `max_length = ..`
+/// - `choice(name = "...", value = ..)`, repeatable
+/// - `channel_types(GuildText, GuildVoice, ..)`
+/// - `autocomplete`
+///
+/// `Option<T>` fields are registered as optional options.
+///
+/// Generated code refers to `::discordant_types`. When depending on the `discordant` crate
+/// instead, point it there with `#[command(crate = "discordant::types")]` on the struct.
+#[proc_macro_derive(CommandOptions, attributes(command))]
+pub fn derive_command_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    options::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `discordant_types::CommandGroup` for an enum whose variants are subcommands.
+///
+/// The enum is the top level command, named after the enum in snake case unless
+/// `#[command(name = "...")]` is given. Each variant becomes a subcommand and either holds a
+/// single `CommandOptions` struct, declares its options as named fields, or has none at all.
+/// Variants marked `#[command(group)]` hold another `CommandGroup` and become subcommand groups.
+///
+/// Descriptions come from doc comments or `#[command(description = "...")]`. As with
+/// `CommandOptions`, `#[command(crate = "discordant::types")]` on the enum points generated code
+/// at the `discordant` crate.
+#[proc_macro_derive(CommandGroup, attributes(command))]
+pub fn derive_command_group(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    group::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
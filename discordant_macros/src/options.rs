@@ -0,0 +1,169 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Fields, GenericParam, Generics, Lifetime, LitStr, Path,
+};
+
+use crate::attr::{choice_value, crate_path, min_max_value, OptionAttrs};
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let lifetime = impl_lifetime(&input.generics)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let impl_generics = if input.generics.params.is_empty() {
+        quote!(<#lifetime>)
+    } else {
+        quote!(#impl_generics)
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "CommandOptions can only be derived for structs",
+            ))
+        }
+    };
+
+    let krate = crate_path(&input.attrs)?;
+    let FieldsExpansion { definitions, parse } = expand_fields(fields, &lifetime, &krate)?;
+
+    Ok(quote! {
+        impl #impl_generics #krate::CommandOptions<#lifetime> for #ident #ty_generics
+        #where_clause
+        {
+            fn options() -> ::std::vec::Vec<#krate::ApplicationCommandOption<'static>> {
+                #definitions
+            }
+
+            fn from_options(
+                options: &#krate::InteractionOptions<#lifetime>,
+            ) -> ::std::result::Result<Self, #krate::OptionError> {
+                ::std::result::Result::Ok(Self #parse)
+            }
+        }
+    })
+}
+
+pub struct FieldsExpansion {
+    /// An expression building the `Vec<ApplicationCommandOption>` for the fields.
+    pub definitions: TokenStream,
+    /// The braces following a struct or variant path that parse every field.
+    pub parse: TokenStream,
+}
+
+pub fn expand_fields(
+    fields: &Fields,
+    lifetime: &Lifetime,
+    krate: &Path,
+) -> syn::Result<FieldsExpansion> {
+    let fields = match fields {
+        Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "command options must have named fields",
+            ))
+        }
+    };
+
+    let mut definitions = Vec::with_capacity(fields.len());
+    let mut parsers = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let attrs = OptionAttrs::parse(&field.attrs)?;
+
+        let name = attrs.rename.clone().unwrap_or_else(|| {
+            let name = ident.to_string();
+            LitStr::new(name.trim_start_matches("r#"), ident.span())
+        });
+
+        let description = attrs.description.clone().ok_or_else(|| {
+            syn::Error::new(
+                field.span(),
+                "missing description, add a doc comment or #[command(description = \"...\")]",
+            )
+        })?;
+
+        let value = quote!(<#ty as #krate::CommandOptionValue<#lifetime>>);
+        let mut builder = quote! {
+            #krate::ApplicationCommandOption::new(#name)
+                .application_command_option_type(#value::OPTION_TYPE)
+                .description(#description)
+                .required(#value::REQUIRED)
+        };
+
+        if let Some(expr) = &attrs.min_value {
+            let value = min_max_value(expr, krate)?;
+            builder.extend(quote!(.min_value(#value)));
+        }
+
+        if let Some(expr) = &attrs.max_value {
+            let value = min_max_value(expr, krate)?;
+            builder.extend(quote!(.max_value(#value)));
+        }
+
+        if let Some(value) = &attrs.min_length {
+            builder.extend(quote!(.min_length(#value)));
+        }
+
+        if let Some(value) = &attrs.max_length {
+            builder.extend(quote!(.max_length(#value)));
+        }
+
+        for (choice_name, expr) in &attrs.choices {
+            let value = choice_value(expr, krate)?;
+            builder.extend(quote! {
+                .choice(#krate::ApplicationCommandOptionChoice::new(#choice_name, #value))
+            });
+        }
+
+        for channel_type in &attrs.channel_types {
+            builder.extend(quote!(.channel_type(#krate::ChannelType::#channel_type)));
+        }
+
+        if attrs.autocomplete {
+            builder.extend(quote!(.autocomplete(true)));
+        }
+
+        definitions.push(builder);
+        parsers.push(quote!(#ident: #value::from_option(options, #name)?));
+    }
+
+    Ok(FieldsExpansion {
+        // Discord rejects commands that list an optional option before a required one
+        definitions: quote! {{
+            let mut options: ::std::vec::Vec<#krate::ApplicationCommandOption<'static>> =
+                ::std::vec![#(#definitions),*];
+            options.sort_by_key(|option| !option.required.unwrap_or_default());
+            options
+        }},
+        parse: quote!({ #(#parsers),* }),
+    })
+}
+
+/// The lifetime options are borrowed for: the type's own lifetime if it has one, otherwise `'a`.
+pub fn impl_lifetime(generics: &Generics) -> syn::Result<Lifetime> {
+    let mut lifetime = None;
+
+    for param in &generics.params {
+        match param {
+            GenericParam::Lifetime(param) if lifetime.is_none() => {
+                lifetime = Some(param.lifetime.clone())
+            }
+            param => {
+                return Err(syn::Error::new(
+                    param.span(),
+                    "only a single lifetime parameter is supported",
+                ))
+            }
+        }
+    }
+
+    Ok(lifetime.unwrap_or_else(|| Lifetime::new("'a", proc_macro2::Span::call_site())))
+}
@@ -1,26 +1,36 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use super::{Snowflake, Team, User};
+use super::{ApplicationMarker, GuildMarker, Id, SkuMarker, Team, User};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Application {
-    pub id: Snowflake,
+    pub id: Id<ApplicationMarker>,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
     pub description: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub rpc_origins: Vec<String>,
     pub bot_public: bool,
     pub bot_require_code_grant: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub terms_of_service_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub privacy_policy_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<User>,
     pub summary: String,
     pub verify_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<Team>,
-    pub guild_id: Option<Snowflake>,
-    pub primary_sku_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_sku_id: Option<Id<SkuMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cover_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<u64>,
 }
@@ -1,12 +1,26 @@
 use std::{borrow::Cow, collections::HashMap};
 
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use crate::ChannelType;
+use crate::{
+    ApplicationMarker, ChannelType, CommandMarker, GuildMarker, Id, Permissions, Snowflake,
+};
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ApplicationCommand<'a> {
+    /// Set by Discord on registered commands.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<CommandMarker>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<Id<ApplicationMarker>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<Snowflake>,
+
     pub name: Cow<'a, str>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,7 +35,7 @@ pub struct ApplicationCommand<'a> {
     pub options: Vec<ApplicationCommandOption<'a>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_member_permissions: Option<Cow<'a, str>>,
+    pub default_member_permissions: Option<Permissions>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dm_permission: Option<bool>,
@@ -73,11 +87,8 @@ impl<'a> ApplicationCommand<'a> {
         self
     }
 
-    pub fn default_member_permissions<T>(mut self, value: T) -> Self
-    where
-        T: Into<Cow<'a, str>>,
-    {
-        self.default_member_permissions = Some(value.into());
+    pub fn default_member_permissions(mut self, value: Permissions) -> Self {
+        self.default_member_permissions = Some(value);
         self
     }
 
@@ -102,13 +113,14 @@ impl<'a> ApplicationCommand<'a> {
     }
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ApplicationCommandTypeDef {
-    #[default]
-    ChatInput = 1,
-    User = 2,
-    Message = 3,
+wire_enum! {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub enum ApplicationCommandTypeDef {
+        #[default]
+        ChatInput = 1,
+        User = 2,
+        Message = 3,
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -242,21 +254,22 @@ impl<'a> ApplicationCommandOption<'a> {
     }
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ApplicationCommandOptionType {
-    #[default]
-    SubCommand = 1,
-    SubCommandGroup = 2,
-    String = 3,
-    Integer = 4,
-    Boolean = 5,
-    User = 6,
-    Channel = 7,
-    Role = 8,
-    Mentionable = 9,
-    Number = 10,
-    Attachment = 11,
+wire_enum! {
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    pub enum ApplicationCommandOptionType {
+        #[default]
+        SubCommand = 1,
+        SubCommandGroup = 2,
+        String = 3,
+        Integer = 4,
+        Boolean = 5,
+        User = 6,
+        Channel = 7,
+        Role = 8,
+        Mentionable = 9,
+        Number = 10,
+        Attachment = 11,
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -265,6 +278,18 @@ pub struct ApplicationCommandOptionChoice<'a> {
     pub value: ApplicationCommandOptionChoiceValue<'a>,
 }
 
+impl<'a> ApplicationCommandOptionChoice<'a> {
+    pub fn new<T>(name: T, value: ApplicationCommandOptionChoiceValue<'a>) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ApplicationCommandOptionChoiceValue<'a> {
@@ -274,6 +299,7 @@ pub enum ApplicationCommandOptionChoiceValue<'a> {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum MinMaxValue {
     Integer(i64),
     Number(f64),
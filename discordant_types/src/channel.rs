@@ -1,145 +1,186 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::{
-    Application, Component, Emoji, GuildMember, MessageInteraction, Role, Snowflake, Sticker,
-    StickerItem, User,
+    Application, ApplicationMarker, AttachmentMarker, ChannelMarker, Component, Emoji,
+    GenericMarker, GuildMarker, GuildMember, Id, MessageInteraction, MessageMarker, Permissions,
+    RoleMarker, Sticker, StickerItem, StickerMarker, Timestamp, User, UserMarker, WebhookMarker,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Channel {
-    pub id: Snowflake,
+    pub id: Id<ChannelMarker>,
     #[serde(rename = "type")]
     pub channel_type: ChannelType,
-    pub guild_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<u64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub permission_overwrites: Vec<Overwrite>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nsfw: Option<bool>,
-    pub last_message_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message_id: Option<Id<MessageMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<u64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub recipients: Vec<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
-    pub owner_id: Option<Snowflake>,
-    pub applicaiton_id: Option<Snowflake>,
-    pub parent_id: Option<Snowflake>,
-    pub last_pin_timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<Id<UserMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<Id<ApplicationMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Id<ChannelMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_pin_timestamp: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rtc_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_quality_mode: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread_metadata: Option<ThreadMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<ThreadMember>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_auto_archive_duration: Option<u64>,
-    pub permissions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ChannelType {
-    GuildText = 0,
-    Dm = 1,
-    GuildVoice = 2,
-    GroupDm = 3,
-    GuildCategory = 4,
-    GuildNews = 5,
-    GuildStore = 6,
-    GuildNewsThread = 10,
-    GuildPublicThread = 11,
-    GuildPrivateThread = 12,
-    GuildStageVoice = 13,
+wire_enum! {
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub enum ChannelType {
+        GuildText = 0,
+        Dm = 1,
+        GuildVoice = 2,
+        GroupDm = 3,
+        GuildCategory = 4,
+        GuildNews = 5,
+        GuildStore = 6,
+        GuildNewsThread = 10,
+        GuildPublicThread = 11,
+        GuildPrivateThread = 12,
+        GuildStageVoice = 13,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Overwrite {
-    pub id: Snowflake,
+    pub id: Id<GenericMarker>,
     #[serde(rename = "type")]
     pub overwrite_type: OverwriteType,
-    pub allow: String,
-    pub deny: String,
+    pub allow: Permissions,
+    pub deny: Permissions,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum OverwriteType {
-    Role = 0,
-    Member = 1,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum OverwriteType {
+        Role = 0,
+        Member = 1,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ThreadMetadata {
     pub archived: bool,
     pub auto_archive_duration: u64,
-    pub archive_timestamp: String,
+    pub archive_timestamp: Timestamp,
     pub locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invitable: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ThreadMember {
-    pub id: Option<Snowflake>,
-    pub user_id: Option<Snowflake>,
-    pub join_timestamp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<ChannelMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<Id<UserMarker>>,
+    pub join_timestamp: Timestamp,
     pub flags: u64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Message {
-    pub id: Snowflake,
-    pub channel_id: Snowflake,
-    pub guild_id: Option<Snowflake>,
+    pub id: Id<MessageMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
     pub author: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<GuildMember>,
     pub content: String,
-    pub timestamp: String,
-    pub edited_timestamp: Option<String>,
+    pub timestamp: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited_timestamp: Option<Timestamp>,
     pub tts: bool,
     pub mention_everyone: bool,
     pub mentions: Vec<User>,
-    pub mention_roles: Vec<Role>,
-    #[serde(default)]
+    pub mention_roles: Vec<Id<RoleMarker>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mention_channels: Vec<ChannelMention>,
     pub attachments: Vec<Attachment>,
     pub embeds: Vec<Embed>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub reactions: Vec<Reaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<Nonce>,
     pub pinned: bool,
-    pub webhook_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<Id<WebhookMarker>>,
     #[serde(rename = "type")]
     pub message_type: MessageType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub activity: Option<MessageActivity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub application: Option<Application>,
-    pub application_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<Id<ApplicationMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message_reference: Option<MessageReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub referenced_message: Option<Box<Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub interaction: Option<MessageInteraction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thread: Option<Channel>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub components: Vec<Component>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sticker_items: Vec<StickerItem>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub stickers: Vec<Sticker>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ChannelMention {
-    pub id: Snowflake,
-    pub guild_id: Snowflake,
+    pub id: Id<ChannelMarker>,
+    pub guild_id: Id<GuildMarker>,
+    #[serde(rename = "type")]
     pub channel_mention_type: ChannelType,
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Attachment {
-    pub id: Snowflake,
+    pub id: Id<AttachmentMarker>,
     pub filename: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -167,7 +208,7 @@ pub struct Embed {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -195,6 +236,9 @@ pub enum EmbedType {
     Gifv,
     Article,
     Link,
+    /// An embed type not known to this version of the crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -267,76 +311,288 @@ pub struct EmbedField {
     pub inline: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Reaction {
     pub count: u64,
     pub me: bool,
     pub emoji: Emoji,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Nonce {
     Integer(u64),
     String(String),
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum MessageType {
-    Default = 0,
-    RecipientAdd = 1,
-    RecipientRemove = 2,
-    Call = 3,
-    ChannelNameChange = 4,
-    ChannelIconChange = 5,
-    ChannelPinnedMessage = 6,
-    GuildMemberJoin = 7,
-    UserPremiumGuildSubscription = 8,
-    UserPremiumGuildSubscriptionTier1 = 9,
-    UserPremiumGuildSubscriptionTier2 = 10,
-    UserPremiumGuildSubscriptionTier3 = 11,
-    ChannelFollowAdd = 12,
-    GuildDiscoveryDisqualified = 14,
-    GuildDiscoveryRequalified = 15,
-    GuildDiscoveryGracePeriodInitialWarning = 16,
-    GuildDiscoveryGracePeriodFinalWarning = 17,
-    ThreadCreated = 18,
-    Reply = 19,
-    ChatInputCommand = 20,
-    ThreadStarterMessage = 21,
-    GuildInviteReminder = 22,
-    ContextMenuCommand = 23,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum MessageType {
+        Default = 0,
+        RecipientAdd = 1,
+        RecipientRemove = 2,
+        Call = 3,
+        ChannelNameChange = 4,
+        ChannelIconChange = 5,
+        ChannelPinnedMessage = 6,
+        GuildMemberJoin = 7,
+        UserPremiumGuildSubscription = 8,
+        UserPremiumGuildSubscriptionTier1 = 9,
+        UserPremiumGuildSubscriptionTier2 = 10,
+        UserPremiumGuildSubscriptionTier3 = 11,
+        ChannelFollowAdd = 12,
+        GuildDiscoveryDisqualified = 14,
+        GuildDiscoveryRequalified = 15,
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        ThreadCreated = 18,
+        Reply = 19,
+        ChatInputCommand = 20,
+        ThreadStarterMessage = 21,
+        GuildInviteReminder = 22,
+        ContextMenuCommand = 23,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MessageActivity {
     #[serde(rename = "type")]
     pub message_activity_type: MessageActivityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub party_id: Option<String>,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum MessageActivityType {
-    Join = 1,
-    Spectate = 2,
-    Listen = 3,
-    JoinRequest = 5,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum MessageActivityType {
+        Join = 1,
+        Spectate = 2,
+        Listen = 3,
+        JoinRequest = 5,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MessageReference {
-    pub message_id: Option<Snowflake>,
-    pub channel_id: Option<Snowflake>,
-    pub guild_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Id<MessageMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Id<ChannelMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fail_if_not_exists: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct AllowedMentions {
     pub parse: Vec<String>,
-    pub roles: Vec<Snowflake>,
-    pub users: Vec<Snowflake>,
+    pub roles: Vec<Id<RoleMarker>>,
+    pub users: Vec<Id<UserMarker>>,
     pub replied_user: bool,
 }
+
+#[derive(Debug, Default, Serialize)]
+pub struct MessageCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sticker_ids: Vec<Id<StickerMarker>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+impl MessageCreate {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn content<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.content = Some(value.into());
+        self
+    }
+
+    pub fn tts(mut self, value: bool) -> Self {
+        self.tts = Some(value);
+        self
+    }
+
+    pub fn embed(mut self, value: Embed) -> Self {
+        self.embeds.push(value);
+        self
+    }
+
+    pub fn allowed_mentions(mut self, value: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(value);
+        self
+    }
+
+    /// Replies to `message_id`.
+    pub fn reply(mut self, message_id: Id<MessageMarker>) -> Self {
+        self.message_reference = Some(MessageReference {
+            message_id: Some(message_id),
+            channel_id: None,
+            guild_id: None,
+            fail_if_not_exists: None,
+        });
+        self
+    }
+
+    pub fn component(mut self, value: Component) -> Self {
+        self.components.push(value);
+        self
+    }
+
+    pub fn sticker(mut self, value: Id<StickerMarker>) -> Self {
+        self.sticker_ids.push(value);
+        self
+    }
+
+    pub fn flags(mut self, value: u64) -> Self {
+        self.flags = Some(value);
+        self
+    }
+}
+
+/// Fields left as `None` are not changed.
+#[derive(Debug, Default, Serialize)]
+pub struct MessageUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+impl MessageUpdate {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn content<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.content = Some(value.into());
+        self
+    }
+
+    pub fn embeds(mut self, value: Vec<Embed>) -> Self {
+        self.embeds = Some(value);
+        self
+    }
+
+    pub fn allowed_mentions(mut self, value: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(value);
+        self
+    }
+
+    pub fn components(mut self, value: Vec<Component>) -> Self {
+        self.components = Some(value);
+        self
+    }
+
+    pub fn flags(mut self, value: u64) -> Self {
+        self.flags = Some(value);
+        self
+    }
+}
+
+/// Fields left as `None` are not changed.
+#[derive(Debug, Default, Serialize)]
+pub struct ChannelUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Id<ChannelMarker>>,
+}
+
+impl ChannelUpdate {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn name<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.name = Some(value.into());
+        self
+    }
+
+    pub fn topic<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.topic = Some(value.into());
+        self
+    }
+
+    pub fn nsfw(mut self, value: bool) -> Self {
+        self.nsfw = Some(value);
+        self
+    }
+
+    pub fn position(mut self, value: u64) -> Self {
+        self.position = Some(value);
+        self
+    }
+
+    pub fn rate_limit_per_user(mut self, value: u64) -> Self {
+        self.rate_limit_per_user = Some(value);
+        self
+    }
+
+    pub fn parent_id(mut self, value: Id<ChannelMarker>) -> Self {
+        self.parent_id = Some(value);
+        self
+    }
+}
@@ -0,0 +1,141 @@
+use super::{
+    ApplicationCommand, ApplicationCommandOption, ApplicationCommandOptionType, Attachment,
+    Channel, GuildMember, InteractionData, InteractionOptions, Mentionable, OptionError,
+    OptionResult, Role, User,
+};
+
+/// A set of command options that can describe itself for registration and be parsed back out of
+/// an interaction. Usually implemented with `#[derive(CommandOptions)]` from `discordant_macros`.
+pub trait CommandOptions<'a>: Sized {
+    fn options() -> Vec<ApplicationCommandOption<'static>>;
+
+    fn from_options(options: &InteractionOptions<'a>) -> Result<Self, OptionError>;
+
+    fn from_data(data: &'a InteractionData) -> Result<Self, OptionError> {
+        Self::from_options(&data.command_options())
+    }
+}
+
+/// A tree of subcommands and subcommand groups, along with the command that holds them.
+/// Usually implemented with `#[derive(CommandGroup)]` from `discordant_macros`.
+pub trait CommandGroup<'a>: Sized {
+    fn command() -> ApplicationCommand<'static>;
+
+    /// Parses the subcommand at `path`, made up of the subcommand group and subcommand names.
+    fn from_path(path: &[&str], options: &InteractionOptions<'a>) -> Result<Self, OptionError>;
+
+    fn from_data(data: &'a InteractionData) -> Result<Self, OptionError> {
+        let options = data.command_options();
+        let path = options.subcommand_path().collect::<Vec<_>>();
+
+        Self::from_path(&path, &options)
+    }
+}
+
+/// A single value that can be read out of the options of an interaction.
+pub trait CommandOptionValue<'a>: Sized {
+    const OPTION_TYPE: ApplicationCommandOptionType;
+    const REQUIRED: bool = true;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError>;
+}
+
+impl<'a, T> CommandOptionValue<'a> for Option<T>
+where
+    T: CommandOptionValue<'a>,
+{
+    const OPTION_TYPE: ApplicationCommandOptionType = T::OPTION_TYPE;
+    const REQUIRED: bool = false;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        T::from_option(options, name).optional()
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for String {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::String;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_str(name).map(str::to_string)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a str {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::String;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_str(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for i64 {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Integer;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_integer(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for f64 {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Number;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_number(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for bool {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Boolean;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_bool(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a User {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::User;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_user(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a GuildMember {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::User;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_member(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a Role {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Role;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_role(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a Channel {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Channel;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_channel(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for &'a Attachment {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Attachment;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_attachment(name)
+    }
+}
+
+impl<'a> CommandOptionValue<'a> for Mentionable<'a> {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Mentionable;
+
+    fn from_option(options: &InteractionOptions<'a>, name: &str) -> Result<Self, OptionError> {
+        options.get_mentionable(name)
+    }
+}
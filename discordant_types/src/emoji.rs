@@ -1,15 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Role, Snowflake, User};
+use super::{EmojiMarker, Id, RoleMarker, User};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Emoji {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<Snowflake>,
+    pub id: Option<Id<EmojiMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub roles: Vec<Role>,
+    pub roles: Vec<Id<RoleMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
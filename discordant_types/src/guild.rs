@@ -2,22 +2,40 @@ use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
-use super::{Snowflake, User};
+use super::{ChannelMarker, Id, Permissions, RoleMarker, Timestamp, User};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct GuildMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nick: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<String>,
-    pub roles: HashSet<Snowflake>,
-    pub joined_at: String,
-    pub premium_since: Option<String>,
+    pub roles: HashSet<Id<RoleMarker>>,
+    pub joined_at: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_since: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deaf: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pending: Option<bool>,
-    pub permissions: Option<String>,
-    pub communication_disabled_until: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub communication_disabled_until: Option<Timestamp>,
+}
+
+impl GuildMember {
+    /// Whether the member is timed out at `now`.
+    pub fn is_timed_out(&self, now: Timestamp) -> bool {
+        self.communication_disabled_until
+            .is_some_and(|until| until > now)
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -26,7 +44,7 @@ pub struct GuildMemberUpdate {
     pub nick: Option<String>,
 
     #[serde(skip_serializing_if = "HashSet::is_empty")]
-    pub roles: HashSet<Snowflake>,
+    pub roles: HashSet<Id<RoleMarker>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deaf: Option<bool>,
@@ -35,10 +53,10 @@ pub struct GuildMemberUpdate {
     pub mute: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<Snowflake>,
+    pub channel_id: Option<Id<ChannelMarker>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub communication_disabled_until: Option<String>,
+    pub communication_disabled_until: Option<Timestamp>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flags: Option<i64>,
@@ -0,0 +1,208 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Snowflake, SnowflakeError, Timestamp};
+
+/// A `Snowflake` for one kind of Discord resource, such as `Id<UserMarker>`.
+///
+/// Serializes exactly like a `Snowflake`. Ids of different kinds cannot be mixed up, and have to
+/// be converted with `cast`.
+pub struct Id<T> {
+    value: Snowflake,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    pub const fn new(value: u64) -> Self {
+        Self::from_snowflake(Snowflake::new(value))
+    }
+
+    pub const fn from_snowflake(value: Snowflake) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    pub const fn get(&self) -> u64 {
+        self.value.get()
+    }
+
+    pub const fn snowflake(&self) -> Snowflake {
+        self.value
+    }
+
+    /// Reinterprets this as the id of another kind of resource.
+    pub const fn cast<U>(self) -> Id<U> {
+        Id::from_snowflake(self.value)
+    }
+
+    /// Milliseconds since the Unix epoch.
+    pub fn timestamp(&self) -> i64 {
+        self.value.timestamp()
+    }
+
+    pub fn created_at(&self) -> Timestamp {
+        self.value.created_at()
+    }
+}
+
+// Implemented by hand, since deriving would require the same of `T`
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T> std::fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Id").field(&self.value.get()).finish()
+    }
+}
+
+impl<T> Display for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> FromStr for Id<T> {
+    type Err = SnowflakeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::from_snowflake)
+    }
+}
+
+impl<T> TryFrom<&str> for Id<T> {
+    type Error = SnowflakeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<T> From<Id<T>> for Snowflake {
+    fn from(value: Id<T>) -> Self {
+        value.value
+    }
+}
+
+impl<T> From<Id<T>> for u64 {
+    fn from(value: Id<T>) -> Self {
+        value.get()
+    }
+}
+
+impl<T> From<Id<T>> for String {
+    fn from(value: Id<T>) -> Self {
+        value.value.into()
+    }
+}
+
+impl<T> Serialize for Id<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Id<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Snowflake::deserialize(deserializer).map(Self::from_snowflake)
+    }
+}
+
+#[derive(Debug)]
+pub enum ApplicationMarker {}
+
+#[derive(Debug)]
+pub enum AttachmentMarker {}
+
+#[derive(Debug)]
+pub enum ChannelMarker {}
+
+#[derive(Debug)]
+pub enum CommandMarker {}
+
+#[derive(Debug)]
+pub enum EmojiMarker {}
+
+#[derive(Debug)]
+pub enum GuildMarker {}
+
+#[derive(Debug)]
+pub enum IntegrationMarker {}
+
+#[derive(Debug)]
+pub enum InteractionMarker {}
+
+#[derive(Debug)]
+pub enum MessageMarker {}
+
+#[derive(Debug)]
+pub enum RoleMarker {}
+
+#[derive(Debug)]
+pub enum SkuMarker {}
+
+#[derive(Debug)]
+pub enum StickerMarker {}
+
+#[derive(Debug)]
+pub enum StickerPackMarker {}
+
+#[derive(Debug)]
+pub enum TeamMarker {}
+
+#[derive(Debug)]
+pub enum UserMarker {}
+
+#[derive(Debug)]
+pub enum WebhookMarker {}
+
+/// For ids that may refer to more than one kind of resource, like the target of a permission
+/// overwrite or a mentionable option.
+#[derive(Debug)]
+pub enum GenericMarker {}
@@ -1,75 +1,152 @@
 use std::{collections::HashMap, fmt::Display};
 
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use super::{Channel, Emoji, GuildMember, Message, Role, Snowflake, User};
+use super::{
+    ApplicationCommandOptionType, ApplicationMarker, Attachment, AttachmentMarker, Channel,
+    ChannelMarker, CommandMarker, Emoji, GenericMarker, GuildMarker, GuildMember, Id,
+    InteractionMarker, Message, MessageMarker, Permissions, Role, RoleMarker, Snowflake, User,
+    UserMarker,
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Interaction {
-    pub id: Snowflake,
-    pub application_id: Snowflake,
+    pub id: Id<InteractionMarker>,
+    pub application_id: Id<ApplicationMarker>,
     #[serde(rename = "type")]
     pub interaction_type: InteractionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<InteractionData>,
-    pub guild_id: Option<Snowflake>,
-    pub channel_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Id<ChannelMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<GuildMember>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
     pub token: String,
     pub version: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Message>,
-    pub app_permissions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_locale: Option<String>,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum InteractionType {
-    Ping = 1,
-    ApplicationCommand = 2,
-    MessageComponent = 3,
-    ApplicationCommandAutocomplete = 4,
-    ModalSubmit = 5,
+wire_enum! {
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub enum InteractionType {
+        Ping = 1,
+        ApplicationCommand = 2,
+        MessageComponent = 3,
+        ApplicationCommandAutocomplete = 4,
+        ModalSubmit = 5,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InteractionData {
-    pub id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id<CommandMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub data_type: Option<ApplicationCommandType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resolved: Option<ResolvedData>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub options: Vec<ApplicationCommandInteractionDataOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub component_type: Option<ComponentType>,
-    #[serde(default)]
-    pub values: Vec<SelectOption>,
-    pub target_id: Option<Snowflake>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Id<GenericMarker>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+}
+
+impl InteractionData {
+    pub fn focused_option(&self) -> Option<FocusedOption> {
+        find_focused(&self.options)
+    }
+
+    pub fn component_value(&self, custom_id: &str) -> Option<&str> {
+        find_component_value(&self.components, custom_id)
+    }
+}
+
+fn find_component_value<'a>(components: &'a [Component], custom_id: &str) -> Option<&'a str> {
+    components.iter().find_map(|component| {
+        if component.custom_id.as_deref() == Some(custom_id) {
+            component.value.as_deref()
+        } else {
+            find_component_value(&component.components, custom_id)
+        }
+    })
+}
+
+fn find_focused(options: &[ApplicationCommandInteractionDataOption]) -> Option<FocusedOption> {
+    options.iter().find_map(|option| {
+        if option.focused() {
+            let value = match option.raw_value() {
+                Some(RawOptionValue::String(v)) => v,
+                Some(RawOptionValue::Integer(v)) => v.to_string(),
+                Some(RawOptionValue::Number(v)) => v.to_string(),
+                Some(RawOptionValue::Boolean(v)) => v.to_string(),
+                None => String::new(),
+            };
+
+            Some(FocusedOption {
+                name: option.name().to_string(),
+                value,
+            })
+        } else {
+            find_focused(option.options())
+        }
+    })
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FocusedOption {
+    pub name: String,
+    pub value: String,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum ApplicationCommandType {
-    ChatInput = 1,
-    User = 2,
-    Message = 3,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum ApplicationCommandType {
+        ChatInput = 1,
+        User = 2,
+        Message = 3,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ResolvedData {
-    pub users: Option<HashMap<Snowflake, User>>,
-    pub members: Option<HashMap<Snowflake, GuildMember>>,
-    pub roles: Option<HashMap<Snowflake, Role>>,
-    pub channels: Option<HashMap<Snowflake, Channel>>,
-    pub messages: Option<HashMap<Snowflake, Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<HashMap<Id<UserMarker>, User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<HashMap<Id<UserMarker>, GuildMember>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<HashMap<Id<RoleMarker>, Role>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<HashMap<Id<ChannelMarker>, Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<HashMap<Id<MessageMarker>, Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<HashMap<Id<AttachmentMarker>, Attachment>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MessageInteraction {
-    pub id: Snowflake,
+    pub id: Id<InteractionMarker>,
     #[serde(rename = "type")]
     pub message_interaction_type: InteractionType,
     pub name: String,
@@ -88,7 +165,7 @@ pub struct Component {
     pub disabled: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub style: Option<ButtonStyle>,
+    pub style: Option<ComponentStyle>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
@@ -111,6 +188,18 @@ pub struct Component {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_values: Option<i64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub components: Vec<Component>,
 }
@@ -140,8 +229,11 @@ impl Component {
         self
     }
 
-    pub fn style(mut self, value: ButtonStyle) -> Self {
-        self.style = Some(value);
+    pub fn style<T>(mut self, value: T) -> Self
+    where
+        T: Into<ComponentStyle>,
+    {
+        self.style = Some(value.into());
         self
     }
 
@@ -189,29 +281,82 @@ impl Component {
         self
     }
 
+    pub fn min_length(mut self, value: i64) -> Self {
+        self.min_length = Some(value);
+        self
+    }
+
+    pub fn max_length(mut self, value: i64) -> Self {
+        self.max_length = Some(value);
+        self
+    }
+
+    pub fn required(mut self, value: bool) -> Self {
+        self.required = Some(value);
+        self
+    }
+
+    pub fn value<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.value = Some(value.into());
+        self
+    }
+
     pub fn component(mut self, value: Component) -> Self {
         self.components.push(value);
         self
     }
 }
 
-#[derive(Debug, Default, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ComponentType {
-    #[default]
-    ActionRow = 1,
-    Button = 2,
-    SelectMenu = 3,
+wire_enum! {
+    #[derive(Debug, Default, Eq, PartialEq)]
+    pub enum ComponentType {
+        #[default]
+        ActionRow = 1,
+        Button = 2,
+        SelectMenu = 3,
+        TextInput = 4,
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ComponentStyle {
+    Button(ButtonStyle),
+    TextInput(TextInputStyle),
+}
+
+impl From<ButtonStyle> for ComponentStyle {
+    fn from(value: ButtonStyle) -> Self {
+        Self::Button(value)
+    }
+}
+
+impl From<TextInputStyle> for ComponentStyle {
+    fn from(value: TextInputStyle) -> Self {
+        Self::TextInput(value)
+    }
+}
+
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum ButtonStyle {
+        Primary = 1,
+        Secondary = 2,
+        Success = 3,
+        Danger = 4,
+        Link = 5,
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ButtonStyle {
-    Primary = 1,
-    Secondary = 2,
-    Success = 3,
-    Danger = 4,
-    Link = 5,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum TextInputStyle {
+        Short = 1,
+        Paragraph = 2,
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -226,175 +371,313 @@ pub struct SelectOption {
     pub default: Option<bool>,
 }
 
-#[derive(Debug)]
-// #[serde(tag = "type")]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ApplicationCommandInteractionDataOption {
-    // #[serde(rename = 1)]
     SubCommand {
         name: String,
         options: Vec<ApplicationCommandInteractionDataOption>,
     },
-    // #[serde(rename = 2)]
     SubCommandGroup {
         name: String,
         options: Vec<ApplicationCommandInteractionDataOption>,
     },
-    // #[serde(rename = 3)]
     String {
         name: String,
         value: Option<String>,
     },
-    // #[serde(rename = 4)]
     Integer {
         name: String,
         value: Option<i64>,
     },
-    // #[serde(rename = 5)]
     Boolean {
         name: String,
         value: Option<bool>,
     },
-    // #[serde(rename = 6)]
     User {
         name: String,
-        value: Option<Snowflake>,
+        value: Option<Id<UserMarker>>,
+    },
+    Channel {
+        name: String,
+        value: Option<Id<ChannelMarker>>,
     },
-    // #[serde(rename = 7)]
-    Channel(Box<ChannelVariant>),
-    // #[serde(rename = 8)]
     Role {
         name: String,
-        value: Option<String>,
+        value: Option<Id<RoleMarker>>,
+    },
+    Mentionable {
+        name: String,
+        value: Option<Id<GenericMarker>>,
     },
-    // #[serde(rename = 9)]
-    // Mentionable {
-    //     name: String,
-    //     value: Option<Mentionable>,
-    // },
-    // #[serde(rename = 10)]
     Number {
         name: String,
         value: Option<f64>,
     },
-    // #[serde(rename = 11)]
-    // Attachment {
-    //     name: String,
-    //     value: Option<Mentionable>,
-    // },
+    Attachment {
+        name: String,
+        value: Option<Id<AttachmentMarker>>,
+    },
+    /// An option type not known to this version of the crate.
+    Unknown {
+        name: String,
+        option_type: u8,
+        value: Option<RawOptionValue>,
+        options: Vec<ApplicationCommandInteractionDataOption>,
+    },
+    /// The option being typed in an autocomplete interaction. Its value is the partial input as
+    /// sent, which need not be valid for `option_type` yet, such as `-` for an integer.
+    Focused {
+        name: String,
+        option_type: ApplicationCommandOptionType,
+        value: Option<RawOptionValue>,
+    },
 }
 
-impl Display for ApplicationCommandInteractionDataOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ApplicationCommandInteractionDataOption {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::SubCommand { name, .. }
+            | Self::SubCommandGroup { name, .. }
+            | Self::String { name, .. }
+            | Self::Integer { name, .. }
+            | Self::Boolean { name, .. }
+            | Self::User { name, .. }
+            | Self::Channel { name, .. }
+            | Self::Role { name, .. }
+            | Self::Mentionable { name, .. }
+            | Self::Number { name, .. }
+            | Self::Attachment { name, .. }
+            | Self::Unknown { name, .. }
+            | Self::Focused { name, .. } => name,
+        }
+    }
+
+    pub fn option_type(&self) -> ApplicationCommandOptionType {
         match self {
-            ApplicationCommandInteractionDataOption::String { name, value, .. } => {
-                write!(f, "{name}-{value:?}")
+            Self::SubCommand { .. } => ApplicationCommandOptionType::SubCommand,
+            Self::SubCommandGroup { .. } => ApplicationCommandOptionType::SubCommandGroup,
+            Self::String { .. } => ApplicationCommandOptionType::String,
+            Self::Integer { .. } => ApplicationCommandOptionType::Integer,
+            Self::Boolean { .. } => ApplicationCommandOptionType::Boolean,
+            Self::User { .. } => ApplicationCommandOptionType::User,
+            Self::Channel { .. } => ApplicationCommandOptionType::Channel,
+            Self::Role { .. } => ApplicationCommandOptionType::Role,
+            Self::Mentionable { .. } => ApplicationCommandOptionType::Mentionable,
+            Self::Number { .. } => ApplicationCommandOptionType::Number,
+            Self::Attachment { .. } => ApplicationCommandOptionType::Attachment,
+            Self::Unknown { option_type, .. } => {
+                ApplicationCommandOptionType::Unknown(*option_type)
             }
-            ApplicationCommandInteractionDataOption::Integer { name, value, .. } => {
-                write!(f, "{name}-{value:?}")
+            Self::Focused { option_type, .. } => option_type.clone(),
+        }
+    }
+
+    pub fn options(&self) -> &[ApplicationCommandInteractionDataOption] {
+        match self {
+            Self::SubCommand { options, .. }
+            | Self::SubCommandGroup { options, .. }
+            | Self::Unknown { options, .. } => options,
+            _ => &[],
+        }
+    }
+
+    pub fn focused(&self) -> bool {
+        matches!(self, Self::Focused { .. })
+    }
+
+    pub fn raw_value(&self) -> Option<RawOptionValue> {
+        match self {
+            Self::SubCommand { .. } | Self::SubCommandGroup { .. } => None,
+            Self::String { value, .. } => value.clone().map(RawOptionValue::String),
+            Self::Integer { value, .. } => value.map(RawOptionValue::Integer),
+            Self::Boolean { value, .. } => value.map(RawOptionValue::Boolean),
+            Self::Number { value, .. } => value.map(RawOptionValue::Number),
+            Self::User { value, .. } => value.map(|id| RawOptionValue::String(id.to_string())),
+            Self::Channel { value, .. } => value.map(|id| RawOptionValue::String(id.to_string())),
+            Self::Role { value, .. } => value.map(|id| RawOptionValue::String(id.to_string())),
+            Self::Mentionable { value, .. } => {
+                value.map(|id| RawOptionValue::String(id.to_string()))
             }
-            ApplicationCommandInteractionDataOption::Boolean { name, value, .. } => {
-                write!(f, "{name}-{value:?}")
+            Self::Attachment { value, .. } => {
+                value.map(|id| RawOptionValue::String(id.to_string()))
             }
-            _ => panic!("to string not implement for type"),
+            Self::Unknown { value, .. } | Self::Focused { value, .. } => value.clone(),
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ChannelVariant {
-    pub name: String,
-    pub value: Option<Channel>,
+impl Display for ApplicationCommandInteractionDataOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name();
+
+        match self.raw_value() {
+            Some(RawOptionValue::String(value)) => write!(f, "{name}-{value:?}"),
+            Some(RawOptionValue::Integer(value)) => write!(f, "{name}-{value:?}"),
+            Some(RawOptionValue::Number(value)) => write!(f, "{name}-{value:?}"),
+            Some(RawOptionValue::Boolean(value)) => write!(f, "{name}-{value:?}"),
+            None => write!(f, "{name}"),
+        }
+    }
+}
+
+/// An option value as sent by Discord, before it is interpreted by its option type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RawOptionValue {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+#[derive(Deserialize)]
+struct RawOption {
+    name: String,
+    #[serde(rename = "type")]
+    option_type: ApplicationCommandOptionType,
+    value: Option<RawOptionValue>,
     #[serde(default)]
-    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    options: Vec<ApplicationCommandInteractionDataOption>,
+    #[serde(default)]
+    focused: bool,
+}
+
+#[derive(Serialize)]
+struct RawOptionRef<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    option_type: ApplicationCommandOptionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<RawOptionValue>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    options: &'a [ApplicationCommandInteractionDataOption],
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    focused: bool,
+}
+
+impl Serialize for ApplicationCommandInteractionDataOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RawOptionRef {
+            name: self.name(),
+            option_type: self.option_type(),
+            value: self.raw_value(),
+            options: self.options(),
+            focused: self.focused(),
+        }
+        .serialize(serializer)
+    }
 }
 
-impl<'de, 'a> serde::Deserialize<'de> for ApplicationCommandInteractionDataOption {
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataOption {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        use serde_json::Value;
-        let d_value = Value::deserialize(deserializer)?;
-
-        let message_type = d_value
-            .get("type")
-            .and_then(Value::as_u64)
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-
-        let name = d_value
-            .get("name")
-            .and_then(Value::as_str)
-            .ok_or_else(|| serde::de::Error::missing_field("name"))?
-            .to_string()
-            .into();
-
-        let options = {
-            let options_raw = d_value.get("options").and_then(Value::as_array);
-
-            if let Some(o) = options_raw {
-                let mut new_options = Vec::with_capacity(o.len());
-
-                for item in o {
-                    let new_item = ApplicationCommandInteractionDataOption::deserialize(item)
-                        .map_err(|e| {
-                            println!("{e:?}");
-                            serde::de::Error::custom("sub option")
-                        })?;
-                    new_options.push(new_item);
-                }
-
-                new_options
-            } else {
-                Vec::with_capacity(0)
-            }
+        use serde::de::{Error, Unexpected};
+
+        let RawOption {
+            name,
+            option_type,
+            value,
+            options,
+            focused,
+        } = RawOption::deserialize(deserializer)?;
+
+        if focused {
+            return Ok(Self::Focused {
+                name,
+                option_type,
+                value,
+            });
+        }
+
+        let unexpected = |value: &RawOptionValue, expected: &str| {
+            let unexpected = match value {
+                RawOptionValue::Boolean(v) => Unexpected::Bool(*v),
+                RawOptionValue::Integer(v) => Unexpected::Signed(*v),
+                RawOptionValue::Number(v) => Unexpected::Float(*v),
+                RawOptionValue::String(v) => Unexpected::Str(v),
+            };
+
+            D::Error::invalid_type(unexpected, &expected)
         };
 
-        let value_raw = d_value.get("value");
+        let snowflake = |value: Option<RawOptionValue>| match value {
+            None => Ok(None),
+            Some(RawOptionValue::String(v)) => v.parse().map(Some).map_err(D::Error::custom),
+            Some(RawOptionValue::Integer(v)) => u64::try_from(v)
+                .map(|v| Some(Snowflake::from(v)))
+                .map_err(|_| D::Error::invalid_value(Unexpected::Signed(v), &"a snowflake")),
+            Some(v) => Err(unexpected(&v, "a snowflake")),
+        };
 
-        Ok(match message_type {
-            1 => ApplicationCommandInteractionDataOption::SubCommand { name, options },
-            3 => ApplicationCommandInteractionDataOption::String {
+        Ok(match option_type {
+            ApplicationCommandOptionType::SubCommand => Self::SubCommand { name, options },
+            ApplicationCommandOptionType::SubCommandGroup => {
+                Self::SubCommandGroup { name, options }
+            }
+            ApplicationCommandOptionType::String => Self::String {
                 name,
-                value: value_raw
-                    .and_then(Value::as_str)
-                    .map(|x| x.to_string().into()),
+                value: match value {
+                    None => None,
+                    Some(RawOptionValue::String(v)) => Some(v),
+                    Some(v) => return Err(unexpected(&v, "a string")),
+                },
             },
-            4 => ApplicationCommandInteractionDataOption::Integer {
+            ApplicationCommandOptionType::Integer => Self::Integer {
                 name,
-                value: value_raw.and_then(Value::as_i64),
+                value: match value {
+                    None => None,
+                    Some(RawOptionValue::Integer(v)) => Some(v),
+                    Some(v) => return Err(unexpected(&v, "an integer")),
+                },
             },
-            5 => ApplicationCommandInteractionDataOption::Boolean {
+            ApplicationCommandOptionType::Boolean => Self::Boolean {
                 name,
-                value: value_raw.and_then(Value::as_bool),
+                value: match value {
+                    None => None,
+                    Some(RawOptionValue::Boolean(v)) => Some(v),
+                    Some(v) => return Err(unexpected(&v, "a boolean")),
+                },
             },
-            6 => ApplicationCommandInteractionDataOption::User {
+            ApplicationCommandOptionType::User => Self::User {
                 name,
-                value: value_raw
-                    .and_then(Value::as_str)
-                    .map(|x| Snowflake::from(x)),
+                value: snowflake(value)?.map(Id::from_snowflake),
             },
-            7 => ApplicationCommandInteractionDataOption::Channel(Box::new(ChannelVariant {
+            ApplicationCommandOptionType::Channel => Self::Channel {
                 name,
-                options,
-                value: if let Some(data) = value_raw {
-                    let channel = Channel::deserialize(data)
-                        .map_err(|_| serde::de::Error::custom("bad channel"))?;
-                    Some(channel)
-                } else {
-                    None
+                value: snowflake(value)?.map(Id::from_snowflake),
+            },
+            ApplicationCommandOptionType::Role => Self::Role {
+                name,
+                value: snowflake(value)?.map(Id::from_snowflake),
+            },
+            ApplicationCommandOptionType::Mentionable => Self::Mentionable {
+                name,
+                value: snowflake(value)?.map(Id::from_snowflake),
+            },
+            ApplicationCommandOptionType::Number => Self::Number {
+                name,
+                value: match value {
+                    None => None,
+                    Some(RawOptionValue::Number(v)) => Some(v),
+                    Some(RawOptionValue::Integer(v)) => Some(v as f64),
+                    Some(v) => return Err(unexpected(&v, "a number")),
                 },
-            })),
-            8 => ApplicationCommandInteractionDataOption::Role {
+            },
+            ApplicationCommandOptionType::Attachment => Self::Attachment {
                 name,
-                value: value_raw
-                    .and_then(Value::as_str)
-                    .map(|x| x.to_string().into()),
+                value: snowflake(value)?.map(Id::from_snowflake),
             },
-            10 => ApplicationCommandInteractionDataOption::Number {
+            ApplicationCommandOptionType::Unknown(option_type) => Self::Unknown {
                 name,
-                value: value_raw.and_then(Value::as_f64),
+                option_type,
+                value,
+                options,
             },
-            type_ => panic!("unsupported type {:?}", type_),
         })
     }
 }
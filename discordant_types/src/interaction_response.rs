@@ -1,7 +1,7 @@
 use serde::Serialize;
 use serde_repr::Serialize_repr;
 
-use crate::Attachment;
+use crate::{ApplicationCommandOptionChoice, Attachment};
 
 use super::{AllowedMentions, Component, Embed};
 
@@ -32,17 +32,18 @@ impl InteractionResponse {
     }
 }
 
-#[derive(Debug, Default, Eq, PartialEq, Serialize_repr)]
-#[repr(u8)]
-pub enum InteractionCallbackType {
-    Pong = 1,
-    #[default]
-    ChannelMessageWithSource = 4,
-    DeferredChannelMessageWithSource = 5,
-    DeferredUpdateMessage = 6,
-    UpdateMessage = 7,
-    ApplicationCommandAutocompleteResult = 8,
-    Modal = 9,
+wire_enum! {
+    #[derive(Debug, Default, Eq, PartialEq)]
+    pub enum InteractionCallbackType {
+        Pong = 1,
+        #[default]
+        ChannelMessageWithSource = 4,
+        DeferredChannelMessageWithSource = 5,
+        DeferredUpdateMessage = 6,
+        UpdateMessage = 7,
+        ApplicationCommandAutocompleteResult = 8,
+        Modal = 9,
+    }
 }
 
 #[derive(Debug, Default, Serialize)]
@@ -67,6 +68,17 @@ pub struct InteractionCallbackData {
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub attachments: Vec<Attachment>,
+
+    /// Always sent once set, since an autocomplete result without matches still needs an empty
+    /// list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choices: Option<Vec<ApplicationCommandOptionChoice<'static>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 impl InteractionCallbackData {
@@ -113,6 +125,35 @@ impl InteractionCallbackData {
         self.attachments.push(value);
         self
     }
+
+    pub fn choice(mut self, value: ApplicationCommandOptionChoice<'static>) -> Self {
+        self.choices.get_or_insert_with(Vec::new).push(value);
+        self
+    }
+
+    pub fn choices<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = ApplicationCommandOptionChoice<'static>>,
+    {
+        self.choices = Some(values.into_iter().collect());
+        self
+    }
+
+    pub fn custom_id<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.custom_id = Some(value.into());
+        self
+    }
+
+    pub fn title<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(value.into());
+        self
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize_repr)]
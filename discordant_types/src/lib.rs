@@ -1,6 +1,12 @@
+#[macro_use]
+mod wire_enum;
+
 mod snowflake;
 pub use snowflake::*;
 
+mod id;
+pub use id::*;
+
 mod application_command;
 pub use application_command::*;
 
@@ -10,6 +16,9 @@ pub use application::*;
 mod channel;
 pub use channel::*;
 
+mod command_options;
+pub use command_options::*;
+
 mod emoji;
 pub use emoji::*;
 
@@ -22,6 +31,9 @@ pub use interaction_response::*;
 mod interaction;
 pub use interaction::*;
 
+mod options;
+pub use options::*;
+
 mod permission;
 pub use permission::*;
 
@@ -31,5 +43,8 @@ pub use sticker::*;
 mod team;
 pub use team::*;
 
+mod timestamp;
+pub use timestamp::*;
+
 mod user;
 pub use user::*;
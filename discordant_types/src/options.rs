@@ -0,0 +1,268 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{
+    ApplicationCommandInteractionDataOption, ApplicationCommandOptionType, Attachment, Channel,
+    GenericMarker, GuildMember, Id, Interaction, InteractionData, ResolvedData, Role, User,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionError {
+    Missing(String),
+    WrongType {
+        name: String,
+        expected: ApplicationCommandOptionType,
+        found: ApplicationCommandOptionType,
+    },
+    Unresolved {
+        name: String,
+        id: Id<GenericMarker>,
+    },
+    UnknownSubcommand(String),
+}
+
+impl Display for OptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptionError::Missing(name) => write!(f, "option `{name}` is missing"),
+            OptionError::WrongType {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "option `{name}` is of type {found:?}, expected {expected:?}"
+            ),
+            OptionError::Unresolved { name, id } => {
+                write!(f, "option `{name}` refers to `{id}` which was not resolved")
+            }
+            OptionError::UnknownSubcommand(path) => write!(f, "unknown subcommand `{path}`"),
+        }
+    }
+}
+
+impl std::error::Error for OptionError {}
+
+pub trait OptionResult<T> {
+    /// Turns a missing option into `Ok(None)`, keeping every other error.
+    fn optional(self) -> Result<Option<T>, OptionError>;
+}
+
+impl<T> OptionResult<T> for Result<T, OptionError> {
+    fn optional(self) -> Result<Option<T>, OptionError> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(OptionError::Missing(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Mentionable<'a> {
+    User(&'a User),
+    Role(&'a Role),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InteractionOptions<'a> {
+    path: [Option<&'a str>; 2],
+    options: &'a [ApplicationCommandInteractionDataOption],
+    resolved: Option<&'a ResolvedData>,
+}
+
+impl Interaction {
+    /// The options of the invoked command, descending into any subcommand group and subcommand.
+    pub fn options(&self) -> InteractionOptions<'_> {
+        match &self.data {
+            Some(data) => data.command_options(),
+            None => InteractionOptions {
+                path: [None; 2],
+                options: &[],
+                resolved: None,
+            },
+        }
+    }
+}
+
+impl InteractionData {
+    pub fn command_options(&self) -> InteractionOptions<'_> {
+        let mut path = [None; 2];
+        let mut options = self.options.as_slice();
+
+        for segment in path.iter_mut() {
+            match options {
+                [ApplicationCommandInteractionDataOption::SubCommandGroup {
+                    name,
+                    options: nested,
+                }]
+                | [ApplicationCommandInteractionDataOption::SubCommand {
+                    name,
+                    options: nested,
+                }] => {
+                    *segment = Some(name.as_str());
+                    options = nested;
+                }
+                _ => break,
+            }
+        }
+
+        InteractionOptions {
+            path,
+            options,
+            resolved: self.resolved.as_ref(),
+        }
+    }
+}
+
+impl<'a> InteractionOptions<'a> {
+    /// The subcommand group and subcommand names leading to these options.
+    pub fn subcommand_path(&self) -> impl Iterator<Item = &'a str> {
+        self.path.into_iter().flatten()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a ApplicationCommandInteractionDataOption> {
+        self.options.iter()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'a ApplicationCommandInteractionDataOption> {
+        self.options.iter().find(|option| option.name() == name)
+    }
+
+    pub fn get_str(&self, name: &str) -> Result<&'a str, OptionError> {
+        match self.find(name, ApplicationCommandOptionType::String)? {
+            ApplicationCommandInteractionDataOption::String {
+                value: Some(value), ..
+            } => Ok(value),
+            _ => Err(OptionError::Missing(name.to_string())),
+        }
+    }
+
+    pub fn get_integer(&self, name: &str) -> Result<i64, OptionError> {
+        match self.find(name, ApplicationCommandOptionType::Integer)? {
+            ApplicationCommandInteractionDataOption::Integer {
+                value: Some(value), ..
+            } => Ok(*value),
+            _ => Err(OptionError::Missing(name.to_string())),
+        }
+    }
+
+    pub fn get_number(&self, name: &str) -> Result<f64, OptionError> {
+        match self.find(name, ApplicationCommandOptionType::Number)? {
+            ApplicationCommandInteractionDataOption::Number {
+                value: Some(value), ..
+            } => Ok(*value),
+            _ => Err(OptionError::Missing(name.to_string())),
+        }
+    }
+
+    pub fn get_bool(&self, name: &str) -> Result<bool, OptionError> {
+        match self.find(name, ApplicationCommandOptionType::Boolean)? {
+            ApplicationCommandInteractionDataOption::Boolean {
+                value: Some(value), ..
+            } => Ok(*value),
+            _ => Err(OptionError::Missing(name.to_string())),
+        }
+    }
+
+    pub fn get_user(&self, name: &str) -> Result<&'a User, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::User)?;
+        self.resolve(name, id, |resolved| resolved.users.as_ref())
+    }
+
+    /// The guild member for a user option. Only present for interactions in a guild.
+    pub fn get_member(&self, name: &str) -> Result<&'a GuildMember, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::User)?;
+        self.resolve(name, id, |resolved| resolved.members.as_ref())
+    }
+
+    pub fn get_role(&self, name: &str) -> Result<&'a Role, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::Role)?;
+        self.resolve(name, id, |resolved| resolved.roles.as_ref())
+    }
+
+    pub fn get_channel(&self, name: &str) -> Result<&'a Channel, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::Channel)?;
+        self.resolve(name, id, |resolved| resolved.channels.as_ref())
+    }
+
+    pub fn get_attachment(&self, name: &str) -> Result<&'a Attachment, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::Attachment)?;
+        self.resolve(name, id, |resolved| resolved.attachments.as_ref())
+    }
+
+    pub fn get_mentionable(&self, name: &str) -> Result<Mentionable<'a>, OptionError> {
+        let id = self.id(name, ApplicationCommandOptionType::Mentionable)?;
+
+        self.resolve(name, id, |resolved| resolved.users.as_ref())
+            .map(Mentionable::User)
+            .or_else(|_| {
+                self.resolve(name, id, |resolved| resolved.roles.as_ref())
+                    .map(Mentionable::Role)
+            })
+    }
+
+    fn find(
+        &self,
+        name: &str,
+        expected: ApplicationCommandOptionType,
+    ) -> Result<&'a ApplicationCommandInteractionDataOption, OptionError> {
+        let option = self
+            .get(name)
+            .ok_or_else(|| OptionError::Missing(name.to_string()))?;
+
+        let found = option.option_type();
+
+        if found != expected {
+            return Err(OptionError::WrongType {
+                name: name.to_string(),
+                expected,
+                found,
+            });
+        }
+
+        Ok(option)
+    }
+
+    fn id(
+        &self,
+        name: &str,
+        expected: ApplicationCommandOptionType,
+    ) -> Result<Id<GenericMarker>, OptionError> {
+        match self.find(name, expected)? {
+            ApplicationCommandInteractionDataOption::User {
+                value: Some(id), ..
+            } => Ok(id.cast()),
+            ApplicationCommandInteractionDataOption::Channel {
+                value: Some(id), ..
+            } => Ok(id.cast()),
+            ApplicationCommandInteractionDataOption::Role {
+                value: Some(id), ..
+            } => Ok(id.cast()),
+            ApplicationCommandInteractionDataOption::Mentionable {
+                value: Some(id), ..
+            } => Ok(*id),
+            ApplicationCommandInteractionDataOption::Attachment {
+                value: Some(id), ..
+            } => Ok(id.cast()),
+            _ => Err(OptionError::Missing(name.to_string())),
+        }
+    }
+
+    fn resolve<M: 'a, T, F>(
+        &self,
+        name: &str,
+        id: Id<GenericMarker>,
+        map: F,
+    ) -> Result<&'a T, OptionError>
+    where
+        F: FnOnce(&'a ResolvedData) -> Option<&'a HashMap<Id<M>, T>>,
+    {
+        self.resolved
+            .and_then(map)
+            .and_then(|values| values.get(&id.cast()))
+            .ok_or_else(|| OptionError::Unresolved {
+                name: name.to_string(),
+                id,
+            })
+    }
+}
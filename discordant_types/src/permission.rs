@@ -1,23 +1,316 @@
-use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
 
-use super::Snowflake;
+use bitflags::bitflags;
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use super::{GuildMarker, Id, IntegrationMarker, Overwrite, OverwriteType, RoleMarker, UserMarker};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Role {
-    pub id: Snowflake,
+    pub id: Id<RoleMarker>,
     pub name: String,
     pub color: u64,
     pub hoist: bool,
     pub position: u64,
-    pub permissions: String,
+    pub permissions: Permissions,
     pub managed: bool,
     pub mentionable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<RoleTags>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RoleTags {
-    pub bot_id: Option<Snowflake>,
-    pub integration_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<Id<UserMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<Id<IntegrationMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_subscriber: Option<()>,
 }
+
+/// Fields left as `None` are not changed, or take Discord's defaults when creating a role.
+#[derive(Debug, Default, Serialize)]
+pub struct RoleUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoist: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentionable: Option<bool>,
+}
+
+impl RoleUpdate {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn name<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.name = Some(value.into());
+        self
+    }
+
+    pub fn permissions(mut self, value: Permissions) -> Self {
+        self.permissions = Some(value);
+        self
+    }
+
+    pub fn color(mut self, value: u64) -> Self {
+        self.color = Some(value);
+        self
+    }
+
+    pub fn hoist(mut self, value: bool) -> Self {
+        self.hoist = Some(value);
+        self
+    }
+
+    pub fn mentionable(mut self, value: bool) -> Self {
+        self.mentionable = Some(value);
+        self
+    }
+}
+
+bitflags! {
+    /// Sent by Discord as a decimal string. Bits without a name are kept, so permissions added by
+    /// Discord later survive a round trip, but `all` only holds the named ones.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+    pub struct Permissions: u64 {
+        const CREATE_INSTANT_INVITE = 1 << 0;
+        const KICK_MEMBERS = 1 << 1;
+        const BAN_MEMBERS = 1 << 2;
+        const ADMINISTRATOR = 1 << 3;
+        const MANAGE_CHANNELS = 1 << 4;
+        const MANAGE_GUILD = 1 << 5;
+        const ADD_REACTIONS = 1 << 6;
+        const VIEW_AUDIT_LOG = 1 << 7;
+        const PRIORITY_SPEAKER = 1 << 8;
+        const STREAM = 1 << 9;
+        const VIEW_CHANNEL = 1 << 10;
+        const SEND_MESSAGES = 1 << 11;
+        const SEND_TTS_MESSAGES = 1 << 12;
+        const MANAGE_MESSAGES = 1 << 13;
+        const EMBED_LINKS = 1 << 14;
+        const ATTACH_FILES = 1 << 15;
+        const READ_MESSAGE_HISTORY = 1 << 16;
+        const MENTION_EVERYONE = 1 << 17;
+        const USE_EXTERNAL_EMOJIS = 1 << 18;
+        const VIEW_GUILD_INSIGHTS = 1 << 19;
+        const CONNECT = 1 << 20;
+        const SPEAK = 1 << 21;
+        const MUTE_MEMBERS = 1 << 22;
+        const DEAFEN_MEMBERS = 1 << 23;
+        const MOVE_MEMBERS = 1 << 24;
+        const USE_VAD = 1 << 25;
+        const CHANGE_NICKNAME = 1 << 26;
+        const MANAGE_NICKNAMES = 1 << 27;
+        const MANAGE_ROLES = 1 << 28;
+        const MANAGE_WEBHOOKS = 1 << 29;
+        const MANAGE_GUILD_EXPRESSIONS = 1 << 30;
+        const USE_APPLICATION_COMMANDS = 1 << 31;
+        const REQUEST_TO_SPEAK = 1 << 32;
+        const MANAGE_EVENTS = 1 << 33;
+        const MANAGE_THREADS = 1 << 34;
+        const CREATE_PUBLIC_THREADS = 1 << 35;
+        const CREATE_PRIVATE_THREADS = 1 << 36;
+        const USE_EXTERNAL_STICKERS = 1 << 37;
+        const SEND_MESSAGES_IN_THREADS = 1 << 38;
+        const USE_EMBEDDED_ACTIVITIES = 1 << 39;
+        const MODERATE_MEMBERS = 1 << 40;
+        const VIEW_CREATOR_MONETIZATION_ANALYTICS = 1 << 41;
+        const USE_SOUNDBOARD = 1 << 42;
+        const CREATE_GUILD_EXPRESSIONS = 1 << 43;
+        const CREATE_EVENTS = 1 << 44;
+        const USE_EXTERNAL_SOUNDS = 1 << 45;
+        const SEND_VOICE_MESSAGES = 1 << 46;
+        const SEND_POLLS = 1 << 49;
+        const USE_EXTERNAL_APPS = 1 << 50;
+    }
+}
+
+impl Permissions {
+    /// What a member who is timed out keeps.
+    pub const TIMED_OUT: Self = Self::VIEW_CHANNEL.union(Self::READ_MESSAGE_HISTORY);
+
+    /// Parses a decimal string, keeping bits without a name.
+    pub fn parse(value: &str) -> Result<Self, std::num::ParseIntError> {
+        value.parse().map(Self::from_bits_retain)
+    }
+}
+
+impl FromStr for Permissions {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for Permissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bits())
+    }
+}
+
+impl Serialize for Permissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+struct PermissionsVisitor;
+
+impl<'de> Visitor<'de> for PermissionsVisitor {
+    type Value = Permissions;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a permission bit set as a decimal string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Permissions::parse(v).map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Permissions::from_bits_retain(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(PermissionsVisitor)
+    }
+}
+
+/// A guild member, and the guild state needed to work out their permissions.
+///
+/// Follows Discord's documented algorithm: the `@everyone` role and the member's roles give the
+/// base permissions, then channel overwrites are applied for `@everyone`, the member's roles and
+/// the member, in that order. Owners and administrators get everything, and members who are timed
+/// out keep only `Permissions::TIMED_OUT`.
+#[derive(Debug, Clone, Copy)]
+pub struct PermissionContext<'a> {
+    pub guild_id: Id<GuildMarker>,
+    pub owner_id: Option<Id<UserMarker>>,
+    /// The guild's roles. The `@everyone` role shares its id with the guild.
+    pub roles: &'a [Role],
+    pub member_id: Id<UserMarker>,
+    pub member_roles: &'a HashSet<Id<RoleMarker>>,
+    pub timed_out: bool,
+}
+
+impl PermissionContext<'_> {
+    pub fn base_permissions(&self) -> Permissions {
+        if self.is_owner() {
+            return Permissions::all();
+        }
+
+        let permissions = self
+            .roles
+            .iter()
+            .filter(|role| role.id == self.everyone() || self.member_roles.contains(&role.id))
+            .fold(Permissions::empty(), |acc, role| acc | role.permissions);
+
+        self.finish(permissions)
+    }
+
+    pub fn channel_permissions(&self, overwrites: &[Overwrite]) -> Permissions {
+        let base = self.base_permissions();
+
+        if self.is_owner() || base.contains(Permissions::ADMINISTRATOR) {
+            return Permissions::all();
+        }
+
+        let mut permissions = base;
+
+        if let Some(everyone) = overwrites
+            .iter()
+            .find(|o| o.overwrite_type == OverwriteType::Role && o.id == self.guild_id.cast())
+        {
+            permissions.remove(everyone.deny);
+            permissions.insert(everyone.allow);
+        }
+
+        let (allow, deny) = overwrites
+            .iter()
+            .filter(|o| {
+                o.overwrite_type == OverwriteType::Role
+                    && o.id.cast() != self.everyone()
+                    && self.member_roles.contains(&o.id.cast())
+            })
+            .fold((Permissions::empty(), Permissions::empty()), |acc, o| {
+                (acc.0 | o.allow, acc.1 | o.deny)
+            });
+
+        permissions.remove(deny);
+        permissions.insert(allow);
+
+        if let Some(member) = overwrites
+            .iter()
+            .find(|o| o.overwrite_type == OverwriteType::Member && o.id == self.member_id.cast())
+        {
+            permissions.remove(member.deny);
+            permissions.insert(member.allow);
+        }
+
+        // Without seeing a channel, nothing else in it applies
+        if !permissions.contains(Permissions::VIEW_CHANNEL) {
+            return Permissions::empty();
+        }
+
+        // Nor can anything that depends on sending a message
+        if !permissions.contains(Permissions::SEND_MESSAGES) {
+            permissions.remove(
+                Permissions::SEND_TTS_MESSAGES
+                    | Permissions::MENTION_EVERYONE
+                    | Permissions::EMBED_LINKS
+                    | Permissions::ATTACH_FILES,
+            );
+        }
+
+        self.finish(permissions)
+    }
+
+    fn everyone(&self) -> Id<RoleMarker> {
+        self.guild_id.cast()
+    }
+
+    fn is_owner(&self) -> bool {
+        self.owner_id == Some(self.member_id)
+    }
+
+    fn finish(&self, permissions: Permissions) -> Permissions {
+        if permissions.contains(Permissions::ADMINISTRATOR) {
+            Permissions::all()
+        } else if self.timed_out {
+            permissions & Permissions::TIMED_OUT
+        } else {
+            permissions
+        }
+    }
+}
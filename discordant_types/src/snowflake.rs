@@ -1,13 +1,99 @@
-use std::fmt::Display;
+use std::{
+    error::Error,
+    fmt::Display,
+    num::ParseIntError,
+    str::FromStr,
+    sync::atomic::{AtomicU16, Ordering},
+};
 
-use serde::{de::Visitor, Deserialize, Serialize};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserialize, Serialize,
+};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+use crate::Timestamp;
+
+const TIMESTAMP_SHIFT: u32 = 22;
+const WORKER_SHIFT: u32 = 17;
+const PROCESS_SHIFT: u32 = 12;
+
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+const MAX_ID: u8 = (1 << 5) - 1;
+const MAX_INCREMENT: u16 = (1 << 12) - 1;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Snowflake(u64);
 
 impl Snowflake {
+    /// Milliseconds since the Unix epoch of the first second of 2015.
+    pub const DISCORD_EPOCH: i64 = 1420070400000;
+
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Builds an id from its parts. `timestamp` is in milliseconds since the Unix epoch, and must
+    /// not be before `DISCORD_EPOCH`.
+    pub fn from_parts(
+        timestamp: i64,
+        worker_id: u8,
+        process_id: u8,
+        increment: u16,
+    ) -> Result<Self, SnowflakeError> {
+        let timestamp = timestamp
+            .checked_sub(Self::DISCORD_EPOCH)
+            .and_then(|t| u64::try_from(t).ok())
+            .filter(|t| *t <= MAX_TIMESTAMP)
+            .ok_or(SnowflakeError::TimestampOutOfRange)?;
+
+        if worker_id > MAX_ID {
+            return Err(SnowflakeError::WorkerIdOutOfRange);
+        }
+
+        if process_id > MAX_ID {
+            return Err(SnowflakeError::ProcessIdOutOfRange);
+        }
+
+        if increment > MAX_INCREMENT {
+            return Err(SnowflakeError::IncrementOutOfRange);
+        }
+
+        Ok(Self(
+            timestamp << TIMESTAMP_SHIFT
+                | u64::from(worker_id) << WORKER_SHIFT
+                | u64::from(process_id) << PROCESS_SHIFT
+                | u64::from(increment),
+        ))
+    }
+
+    /// The smallest id created at `timestamp`, for use as a `before` or `after` pagination cursor.
+    pub fn from_timestamp(timestamp: i64) -> Result<Self, SnowflakeError> {
+        Self::from_parts(timestamp, 0, 0, 0)
+    }
+
+    pub const fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Milliseconds since the Unix epoch.
     pub fn timestamp(&self) -> i64 {
-        (self.0 as i64 >> 22) + 1420070400000
+        (self.0 >> TIMESTAMP_SHIFT) as i64 + Self::DISCORD_EPOCH
+    }
+
+    pub fn created_at(&self) -> Timestamp {
+        Timestamp::from_unix_millis(self.timestamp())
+    }
+
+    pub const fn worker_id(&self) -> u8 {
+        ((self.0 >> WORKER_SHIFT) & MAX_ID as u64) as u8
+    }
+
+    pub const fn process_id(&self) -> u8 {
+        ((self.0 >> PROCESS_SHIFT) & MAX_ID as u64) as u8
+    }
+
+    pub const fn increment(&self) -> u16 {
+        (self.0 & MAX_INCREMENT as u64) as u16
     }
 
     pub fn bytes(&self) -> [u8; 8] {
@@ -21,37 +107,133 @@ impl Display for Snowflake {
     }
 }
 
-impl From<&str> for Snowflake {
-    fn from(s: &str) -> Self {
-        let value = s.parse().unwrap();
+impl FromStr for Snowflake {
+    type Err = SnowflakeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self).map_err(SnowflakeError::Parse)
+    }
+}
+
+impl TryFrom<&str> for Snowflake {
+    type Error = SnowflakeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Self {
         Self(value)
     }
 }
 
+impl From<Snowflake> for u64 {
+    fn from(Snowflake(value): Snowflake) -> Self {
+        value
+    }
+}
+
 impl From<Snowflake> for String {
     fn from(Snowflake(value): Snowflake) -> Self {
         value.to_string()
     }
 }
 
+/// Creates ids locally, counting up the increment for each id, like Discord does.
+#[derive(Debug)]
+pub struct SnowflakeGenerator {
+    worker_id: u8,
+    process_id: u8,
+    increment: AtomicU16,
+}
+
+impl SnowflakeGenerator {
+    pub fn new(worker_id: u8, process_id: u8) -> Result<Self, SnowflakeError> {
+        // Validates the ids up front, so `generate` only fails on the timestamp
+        Snowflake::from_parts(Snowflake::DISCORD_EPOCH, worker_id, process_id, 0)?;
+
+        Ok(Self {
+            worker_id,
+            process_id,
+            increment: AtomicU16::new(0),
+        })
+    }
+
+    /// `timestamp` is in milliseconds since the Unix epoch.
+    pub fn generate(&self, timestamp: i64) -> Result<Snowflake, SnowflakeError> {
+        let increment = self.increment.fetch_add(1, Ordering::Relaxed) & MAX_INCREMENT;
+
+        Snowflake::from_parts(timestamp, self.worker_id, self.process_id, increment)
+    }
+
+    pub fn generate_now(&self) -> Result<Snowflake, SnowflakeError> {
+        self.generate(Timestamp::now().unix_millis())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnowflakeError {
+    Parse(ParseIntError),
+    TimestampOutOfRange,
+    WorkerIdOutOfRange,
+    ProcessIdOutOfRange,
+    IncrementOutOfRange,
+}
+
+impl Display for SnowflakeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "Could not parse snowflake: {e}"),
+            Self::TimestampOutOfRange => write!(f, "Timestamp out of range for a snowflake"),
+            Self::WorkerIdOutOfRange => write!(f, "Worker id must be at most {MAX_ID}"),
+            Self::ProcessIdOutOfRange => write!(f, "Process id must be at most {MAX_ID}"),
+            Self::IncrementOutOfRange => write!(f, "Increment must be at most {MAX_INCREMENT}"),
+        }
+    }
+}
+
+impl Error for SnowflakeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 struct SnowflakeVisitor;
 
 impl<'de> Visitor<'de> for SnowflakeVisitor {
     type Value = Snowflake;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a str to convert to a i64")
+        formatter.write_str("a snowflake, as a string or an integer")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
     {
-        let value = v
-            .parse::<u64>()
-            .map_err(|e| E::custom(format!("Could not parse `{v}`: {}", e)))?;
+        v.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Snowflake(v))
+    }
 
-        Ok(Snowflake(value))
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u64::try_from(v)
+            .map(Snowflake)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
     }
 }
 
@@ -69,6 +251,6 @@ impl<'de> Deserialize<'de> for Snowflake {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(SnowflakeVisitor)
+        deserializer.deserialize_any(SnowflakeVisitor)
     }
 }
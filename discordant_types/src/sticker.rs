@@ -1,43 +1,51 @@
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde::{Deserialize, Serialize};
 
-use super::{Snowflake, User};
+use super::{GuildMarker, Id, StickerMarker, StickerPackMarker, User};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct StickerItem {
-    pub id: Snowflake,
+    pub id: Id<StickerMarker>,
     pub name: String,
     pub format_type: StickerFormat,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum StickerFormat {
-    Png = 1,
-    Apng = 2,
-    Lottie = 3,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum StickerFormat {
+        Png = 1,
+        Apng = 2,
+        Lottie = 3,
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Sticker {
-    pub id: Snowflake,
-    pub pack_id: Option<Snowflake>,
+    pub id: Id<StickerMarker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack_id: Option<Id<StickerPackMarker>>,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub tags: String,
-    pub asset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
     #[serde(rename = "type")]
     pub sticker_type: StickerType,
     pub format_type: StickerFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub available: Option<bool>,
-    pub guild_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_value: Option<u64>,
 }
 
-#[derive(Debug, Eq, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum StickerType {
-    Standard = 1,
-    Guild = 2,
+wire_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum StickerType {
+        Standard = 1,
+        Guild = 2,
+    }
 }
@@ -1,20 +1,21 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use super::{Snowflake, User};
+use super::{Id, TeamMarker, User, UserMarker};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Team {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
-    pub id: Snowflake,
+    pub id: Id<TeamMarker>,
     pub members: Vec<TeamMember>,
     pub name: String,
-    pub owner_user_id: Snowflake,
+    pub owner_user_id: Id<UserMarker>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TeamMember {
     pub membership_state: u64,
     pub permissions: Vec<String>,
-    pub team_id: Snowflake,
+    pub team_id: Id<TeamMarker>,
     pub user: User,
 }
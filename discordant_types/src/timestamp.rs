@@ -0,0 +1,384 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// An ISO8601 timestamp, as sent by Discord, with microsecond precision.
+///
+/// Parses timestamps with or without fractional seconds, and with any UTC offset. Always
+/// serializes in UTC with six fractional digits, like `2021-05-25T19:57:05.214000+00:00`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Timestamp {
+    micros: i64,
+}
+
+impl Timestamp {
+    pub const UNIX_EPOCH: Self = Self { micros: 0 };
+
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
+    pub const fn from_unix_millis(millis: i64) -> Self {
+        Self {
+            micros: millis.saturating_mul(1000),
+        }
+    }
+
+    pub const fn from_unix_micros(micros: i64) -> Self {
+        Self { micros }
+    }
+
+    pub const fn unix_millis(&self) -> i64 {
+        self.micros.div_euclid(1000)
+    }
+
+    pub const fn unix_micros(&self) -> i64 {
+        self.micros
+    }
+
+    pub fn parse(value: &str) -> Result<Self, TimestampParseError> {
+        parse_iso8601(value.as_bytes()).ok_or(TimestampParseError)
+    }
+
+    /// `self + duration`, saturating at the bounds of the representation.
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        let micros = i64::try_from(duration.as_micros()).unwrap_or(i64::MAX);
+
+        Self {
+            micros: self.micros.saturating_add(micros),
+        }
+    }
+
+    /// `self - duration`, saturating at the bounds of the representation.
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        let micros = i64::try_from(duration.as_micros()).unwrap_or(i64::MAX);
+
+        Self {
+            micros: self.micros.saturating_sub(micros),
+        }
+    }
+
+    /// How long after `earlier` this is, or `None` if it is before `earlier`.
+    pub fn duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        let micros = self.micros.checked_sub(earlier.micros)?;
+
+        u64::try_from(micros).ok().map(Duration::from_micros)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.micros.div_euclid(MICROS_PER_SECOND);
+        let fraction = self.micros.rem_euclid(MICROS_PER_SECOND);
+        let days = seconds.div_euclid(SECONDS_PER_DAY);
+        let time = seconds.rem_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{fraction:06}+00:00",
+            time / 3600,
+            time / 60 % 60,
+            time % 60,
+        )
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(value: SystemTime) -> Self {
+        let micros = match value.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_micros()).unwrap_or(i64::MAX),
+            Err(e) => i64::try_from(e.duration().as_micros())
+                .map(|before| -before)
+                .unwrap_or(i64::MIN),
+        };
+
+        Self { micros }
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(value: Timestamp) -> Self {
+        let offset = Duration::from_micros(value.micros.unsigned_abs());
+
+        if value.micros < 0 {
+            UNIX_EPOCH - offset
+        } else {
+            UNIX_EPOCH + offset
+        }
+    }
+}
+
+/// Out of range values saturate to chrono's minimum or maximum date.
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(value: Timestamp) -> Self {
+        chrono::DateTime::from_timestamp_micros(value.micros).unwrap_or(if value.micros < 0 {
+            chrono::DateTime::<chrono::Utc>::MIN_UTC
+        } else {
+            chrono::DateTime::<chrono::Utc>::MAX_UTC
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Timestamp
+where
+    Tz: chrono::TimeZone,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self::from_unix_micros(value.timestamp_micros())
+    }
+}
+
+/// Out of range values saturate to time's minimum or maximum date.
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(value: Timestamp) -> Self {
+        let nanos = i128::from(value.micros) * 1000;
+
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap_or(if nanos < 0 {
+            time::PrimitiveDateTime::MIN.assume_utc()
+        } else {
+            time::PrimitiveDateTime::MAX.assume_utc()
+        })
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(value: time::OffsetDateTime) -> Self {
+        let micros = value.unix_timestamp_nanos().div_euclid(1000);
+
+        Self::from_unix_micros(i64::try_from(micros).unwrap_or(if micros < 0 {
+            i64::MIN
+        } else {
+            i64::MAX
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TimestampParseError;
+
+impl Display for TimestampParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid ISO8601 timestamp")
+    }
+}
+
+impl Error for TimestampParseError {}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an ISO8601 timestamp")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Timestamp::parse(v).map_err(|e| E::custom(format!("Could not parse `{v}`: {e}")))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TimestampVisitor)
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)`
+fn parse_iso8601(s: &[u8]) -> Option<Timestamp> {
+    let mut cursor = Cursor(s);
+
+    let year = cursor.digits(4)?;
+    cursor.expect(b'-')?;
+    let month = cursor.digits(2)?;
+    cursor.expect(b'-')?;
+    let day = cursor.digits(2)?;
+    cursor.expect_any(b"Tt ")?;
+    let hour = cursor.digits(2)?;
+    cursor.expect(b':')?;
+    let minute = cursor.digits(2)?;
+    cursor.expect(b':')?;
+    let second = cursor.digits(2)?;
+
+    let mut micros = 0;
+
+    if cursor.expect(b'.').is_some() {
+        let fraction = cursor.take_while(|c| c.is_ascii_digit());
+
+        if fraction.is_empty() {
+            return None;
+        }
+
+        // Anything past microseconds is truncated
+        for i in 0..6 {
+            let digit = fraction.get(i).map_or(0, |c| i64::from(c - b'0'));
+            micros = micros * 10 + digit;
+        }
+    }
+
+    let offset = match cursor.next()? {
+        b'Z' | b'z' => 0,
+        sign @ (b'+' | b'-') => {
+            let hours = cursor.digits(2)?;
+            cursor.expect(b':')?;
+            let minutes = cursor.digits(2)?;
+
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+
+            let offset = hours * 3600 + minutes * 60;
+
+            if sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+
+    if !cursor.0.is_empty()
+        || !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let seconds =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second
+            - offset;
+
+    Some(Timestamp::from_unix_micros(
+        seconds * MICROS_PER_SECOND + micros,
+    ))
+}
+
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    fn next(&mut self) -> Option<u8> {
+        let (&first, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(first)
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        self.expect_any(&[c])
+    }
+
+    fn expect_any(&mut self, options: &[u8]) -> Option<()> {
+        match self.0.split_first() {
+            Some((first, rest)) if options.contains(first) => {
+                self.0 = rest;
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn digits(&mut self, count: usize) -> Option<i64> {
+        let digits = self.0.get(..count)?;
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.0 = &self.0[count..];
+
+        Some(
+            digits
+                .iter()
+                .fold(0, |acc, c| acc * 10 + i64::from(c - b'0')),
+        )
+    }
+
+    fn take_while(&mut self, f: impl Fn(&u8) -> bool) -> &'a [u8] {
+        let len = self.0.iter().take_while(|c| f(c)).count();
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        taken
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
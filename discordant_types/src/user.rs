@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use super::Snowflake;
+use super::{Id, UserMarker};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct User {
-    pub id: Snowflake,
+    pub id: Id<UserMarker>,
     pub username: String,
     pub discriminator: String,
 
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use discordant_types::{
+    GenericMarker, GuildMarker, Id, RoleMarker, Snowflake, SnowflakeError, UserMarker,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Member {
+    user_id: Id<UserMarker>,
+    roles: Vec<Id<RoleMarker>>,
+    guild_id: Option<Id<GuildMarker>>,
+}
+
+#[test]
+fn json_round_trip() {
+    let json = r#"{"user_id":"80351110224678912","roles":["41771983423143936"],"guild_id":null}"#;
+    let member = serde_json::from_str::<Member>(json).unwrap();
+
+    assert_eq!(
+        member,
+        Member {
+            user_id: Id::new(80351110224678912),
+            roles: vec![Id::new(41771983423143936)],
+            guild_id: None,
+        }
+    );
+    assert_eq!(serde_json::to_string(&member).unwrap(), json);
+
+    // Serialized just like the snowflake it holds
+    let id = Id::<UserMarker>::new(u64::MAX);
+    assert_eq!(
+        serde_json::to_value(id).unwrap(),
+        serde_json::to_value(Snowflake::new(u64::MAX)).unwrap()
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        "80351110224678912".parse::<Id<UserMarker>>(),
+        Ok(Id::new(80351110224678912))
+    );
+    assert_eq!(
+        Id::<UserMarker>::try_from("18446744073709551615"),
+        Ok(Id::new(u64::MAX))
+    );
+
+    for value in ["", "-1", "abc", "18446744073709551616"] {
+        assert!(
+            matches!(
+                value.parse::<Id<UserMarker>>(),
+                Err(SnowflakeError::Parse(_))
+            ),
+            "`{value}` should not parse"
+        );
+    }
+
+    let id = Id::<GuildMarker>::new(41771983423143937);
+    assert_eq!(id.to_string(), "41771983423143937");
+    assert_eq!(id.to_string().parse(), Ok(id));
+}
+
+#[test]
+fn cast() {
+    let guild_id = Id::<GuildMarker>::new(41771983423143937);
+
+    // The `@everyone` role shares its guild's id
+    let everyone: Id<RoleMarker> = guild_id.cast();
+    assert_eq!(everyone.get(), guild_id.get());
+    assert_eq!(everyone.snowflake(), guild_id.snowflake());
+    assert_eq!(
+        everyone.cast::<GenericMarker>().cast::<GuildMarker>(),
+        guild_id
+    );
+    assert_eq!(everyone.created_at(), guild_id.created_at());
+}
+
+#[test]
+fn conversions() {
+    let id = Id::<UserMarker>::from_snowflake(Snowflake::new(80351110224678912));
+
+    assert_eq!(id, Id::new(80351110224678912));
+    assert_eq!(u64::from(id), 80351110224678912);
+    assert_eq!(Snowflake::from(id), Snowflake::new(80351110224678912));
+    assert_eq!(String::from(id), "80351110224678912");
+
+    let ids: HashSet<_> = [id, Id::new(80351110224678912), Id::new(1)].into();
+    assert_eq!(ids.len(), 2);
+    assert!(Id::<UserMarker>::new(1) < id);
+}
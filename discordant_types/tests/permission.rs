@@ -0,0 +1,356 @@
+use std::collections::HashSet;
+
+use discordant_types::{
+    Id, Overwrite, OverwriteType, PermissionContext, Permissions, Role, RoleMarker,
+};
+
+const GUILD: u64 = 1;
+const OWNER: u64 = 2;
+const MEMBER: u64 = 3;
+const MODS: u64 = 10;
+const MUTED: u64 = 11;
+const ADMINS: u64 = 12;
+
+fn role(id: u64, permissions: Permissions) -> Role {
+    Role {
+        id: Id::new(id),
+        name: format!("role {id}"),
+        color: 0,
+        hoist: false,
+        position: 0,
+        permissions,
+        managed: false,
+        mentionable: false,
+        tags: None,
+    }
+}
+
+fn overwrite(
+    id: u64,
+    overwrite_type: OverwriteType,
+    allow: Permissions,
+    deny: Permissions,
+) -> Overwrite {
+    Overwrite {
+        id: Id::new(id),
+        overwrite_type,
+        allow,
+        deny,
+    }
+}
+
+fn roles() -> Vec<Role> {
+    vec![
+        role(
+            GUILD,
+            Permissions::VIEW_CHANNEL
+                | Permissions::SEND_MESSAGES
+                | Permissions::READ_MESSAGE_HISTORY
+                | Permissions::EMBED_LINKS
+                | Permissions::ATTACH_FILES,
+        ),
+        role(
+            MODS,
+            Permissions::MANAGE_MESSAGES | Permissions::KICK_MEMBERS,
+        ),
+        role(MUTED, Permissions::empty()),
+        role(ADMINS, Permissions::ADMINISTRATOR),
+    ]
+}
+
+fn member_roles(ids: &[u64]) -> HashSet<Id<RoleMarker>> {
+    ids.iter().copied().map(Id::new).collect()
+}
+
+fn context<'a>(
+    roles: &'a [Role],
+    member_roles: &'a HashSet<Id<RoleMarker>>,
+) -> PermissionContext<'a> {
+    PermissionContext {
+        guild_id: Id::new(GUILD),
+        owner_id: Some(Id::new(OWNER)),
+        roles,
+        member_id: Id::new(MEMBER),
+        member_roles,
+        timed_out: false,
+    }
+}
+
+#[test]
+fn base_combines_everyone_and_member_roles() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS]);
+    let base = context(&roles, &member_roles).base_permissions();
+
+    assert!(base.contains(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES));
+    assert!(base.contains(Permissions::MANAGE_MESSAGES | Permissions::KICK_MEMBERS));
+    assert!(!base.contains(Permissions::BAN_MEMBERS));
+
+    let no_roles = HashSet::new();
+    let base = context(&roles, &no_roles).base_permissions();
+
+    assert!(!base.contains(Permissions::MANAGE_MESSAGES));
+}
+
+#[test]
+fn everyone_overwrite() {
+    let roles = roles();
+    let member_roles = member_roles(&[]);
+    let overwrites = [overwrite(
+        GUILD,
+        OverwriteType::Role,
+        Permissions::ADD_REACTIONS,
+        Permissions::EMBED_LINKS,
+    )];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::ADD_REACTIONS));
+    assert!(!permissions.contains(Permissions::EMBED_LINKS));
+    assert!(permissions.contains(Permissions::SEND_MESSAGES));
+}
+
+#[test]
+fn everyone_overwrite_must_be_a_role() {
+    let roles = roles();
+    let member_roles = member_roles(&[]);
+
+    // A member who happens to share the guild's id is not `@everyone`
+    let overwrites = [overwrite(
+        GUILD,
+        OverwriteType::Member,
+        Permissions::empty(),
+        Permissions::SEND_MESSAGES,
+    )];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::SEND_MESSAGES));
+}
+
+#[test]
+fn role_overwrites_are_combined_before_applying() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS, MUTED]);
+    let overwrites = [
+        overwrite(
+            MUTED,
+            OverwriteType::Role,
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+        ),
+        // An allow from any role wins over a deny from another
+        overwrite(
+            MODS,
+            OverwriteType::Role,
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        ),
+        // Roles the member does not have are ignored
+        overwrite(
+            ADMINS,
+            OverwriteType::Role,
+            Permissions::empty(),
+            Permissions::VIEW_CHANNEL,
+        ),
+    ];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES));
+    assert!(!permissions.contains(Permissions::ADD_REACTIONS));
+}
+
+#[test]
+fn role_overwrites_apply_after_everyone() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS]);
+    let overwrites = [
+        overwrite(
+            GUILD,
+            OverwriteType::Role,
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES,
+        ),
+        overwrite(
+            MODS,
+            OverwriteType::Role,
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        ),
+    ];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::SEND_MESSAGES));
+}
+
+#[test]
+fn member_overwrite_applies_last() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS]);
+    let overwrites = [
+        overwrite(
+            MEMBER,
+            OverwriteType::Member,
+            Permissions::MANAGE_THREADS,
+            Permissions::MANAGE_MESSAGES,
+        ),
+        overwrite(
+            MODS,
+            OverwriteType::Role,
+            Permissions::MANAGE_MESSAGES,
+            Permissions::MANAGE_THREADS,
+        ),
+        // Another member's overwrite does not apply
+        overwrite(
+            OWNER,
+            OverwriteType::Member,
+            Permissions::empty(),
+            Permissions::VIEW_CHANNEL,
+        ),
+    ];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::MANAGE_THREADS));
+    assert!(!permissions.contains(Permissions::MANAGE_MESSAGES));
+    assert!(permissions.contains(Permissions::VIEW_CHANNEL));
+}
+
+#[test]
+fn without_view_channel_nothing_applies() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS]);
+    let overwrites = [overwrite(
+        GUILD,
+        OverwriteType::Role,
+        Permissions::empty(),
+        Permissions::VIEW_CHANNEL,
+    )];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert_eq!(permissions, Permissions::empty());
+}
+
+#[test]
+fn without_send_messages_dependents_are_removed() {
+    let roles = roles();
+    let member_roles = member_roles(&[]);
+    let overwrites = [overwrite(
+        MEMBER,
+        OverwriteType::Member,
+        Permissions::SEND_TTS_MESSAGES | Permissions::MENTION_EVERYONE,
+        Permissions::SEND_MESSAGES,
+    )];
+
+    let permissions = context(&roles, &member_roles).channel_permissions(&overwrites);
+
+    assert!(permissions.contains(Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY));
+    assert!(!permissions.intersects(
+        Permissions::SEND_MESSAGES
+            | Permissions::SEND_TTS_MESSAGES
+            | Permissions::MENTION_EVERYONE
+            | Permissions::EMBED_LINKS
+            | Permissions::ATTACH_FILES
+    ));
+}
+
+#[test]
+fn administrator_ignores_overwrites() {
+    let roles = roles();
+    let member_roles = member_roles(&[ADMINS]);
+    let overwrites = [overwrite(
+        MEMBER,
+        OverwriteType::Member,
+        Permissions::empty(),
+        Permissions::VIEW_CHANNEL,
+    )];
+    let context = context(&roles, &member_roles);
+
+    assert_eq!(context.base_permissions(), Permissions::all());
+    assert_eq!(context.channel_permissions(&overwrites), Permissions::all());
+}
+
+#[test]
+fn owner_ignores_overwrites() {
+    let roles = roles();
+    let member_roles = member_roles(&[]);
+    let overwrites = [overwrite(
+        GUILD,
+        OverwriteType::Role,
+        Permissions::empty(),
+        Permissions::VIEW_CHANNEL,
+    )];
+    let context = PermissionContext {
+        member_id: Id::new(OWNER),
+        timed_out: true,
+        ..context(&roles, &member_roles)
+    };
+
+    assert_eq!(context.base_permissions(), Permissions::all());
+    assert_eq!(context.channel_permissions(&overwrites), Permissions::all());
+}
+
+#[test]
+fn timed_out_members_can_only_read() {
+    let roles = roles();
+    let member_roles = member_roles(&[MODS]);
+    let overwrites = [overwrite(
+        MEMBER,
+        OverwriteType::Member,
+        Permissions::ADD_REACTIONS,
+        Permissions::empty(),
+    )];
+    let context = PermissionContext {
+        timed_out: true,
+        ..context(&roles, &member_roles)
+    };
+
+    assert_eq!(context.base_permissions(), Permissions::TIMED_OUT);
+    assert_eq!(
+        context.channel_permissions(&overwrites),
+        Permissions::TIMED_OUT
+    );
+}
+
+#[test]
+fn timed_out_administrators_keep_everything() {
+    let roles = roles();
+    let member_roles = member_roles(&[ADMINS]);
+    let context = PermissionContext {
+        timed_out: true,
+        ..context(&roles, &member_roles)
+    };
+
+    assert_eq!(context.channel_permissions(&[]), Permissions::all());
+}
+
+#[test]
+fn everything_is_every_named_permission() {
+    let roles = roles();
+    let member_roles = member_roles(&[ADMINS]);
+    let everything = context(&roles, &member_roles).channel_permissions(&[]);
+
+    assert_eq!(everything.bits(), ((1 << 47) - 1) | (1 << 49) | (1 << 50));
+    assert_eq!(everything.to_string(), "1829587348619263");
+    assert_eq!(
+        serde_json::from_value::<Permissions>(serde_json::to_value(everything).unwrap()).unwrap(),
+        everything
+    );
+}
+
+#[test]
+fn unknown_bits_are_kept() {
+    let permissions = "9007199254741001".parse::<Permissions>().unwrap();
+
+    assert_eq!(permissions.bits(), (1 << 53) | 0b1001);
+    assert!(permissions.contains(Permissions::CREATE_INSTANT_INVITE | Permissions::ADMINISTRATOR));
+    assert_eq!(permissions.to_string(), "9007199254741001");
+    assert_eq!(
+        serde_json::to_string(&permissions).unwrap(),
+        r#""9007199254741001""#
+    );
+    assert!(!Permissions::all().contains(permissions));
+}
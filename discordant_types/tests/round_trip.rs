@@ -0,0 +1,193 @@
+use std::{fs, path::Path};
+
+use discordant_types::{
+    Application, ApplicationCommandInteractionDataOption, ApplicationCommandOptionType, Channel,
+    ChannelType, EmbedType, Interaction, InteractionData, Message, MessageType, RawOptionValue,
+    Sticker, Timestamp,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+fn round_trip<T>(dir: &str)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/payloads")
+        .join(dir);
+
+    let mut paths = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    assert!(!paths.is_empty(), "no payloads in {}", dir.display());
+
+    for path in paths {
+        let json = fs::read_to_string(&path).unwrap();
+        let original: Value = serde_json::from_str(&json).unwrap();
+
+        let first: T =
+            serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let first = serde_json::to_value(first).unwrap();
+
+        let mut diffs = Vec::new();
+        compare(
+            "",
+            &canonical(original),
+            &canonical(first.clone()),
+            &mut diffs,
+        );
+        assert!(
+            diffs.is_empty(),
+            "{} does not serialize back to itself:\n{}",
+            path.display(),
+            diffs.join("\n")
+        );
+
+        let second: T = serde_json::from_value(first.clone())
+            .unwrap_or_else(|e| panic!("{}: reserialized: {e}", path.display()));
+        let second = serde_json::to_value(second).unwrap();
+
+        assert_eq!(
+            canonical(first),
+            canonical(second),
+            "{} does not round-trip",
+            path.display()
+        );
+    }
+}
+
+/// Collects where `serialized` differs from `original`. Fields of `original` that the types don't
+/// model may be left out, but anything that was serialized must have been in the payload.
+fn compare(path: &str, original: &Value, serialized: &Value, diffs: &mut Vec<String>) {
+    match (original, serialized) {
+        (Value::Object(original), Value::Object(serialized)) => {
+            for (key, value) in serialized {
+                let path = format!("{path}.{key}");
+
+                match original.get(key) {
+                    Some(original) => compare(&path, original, value, diffs),
+                    None => diffs.push(format!("{path}: {value} was not in the payload")),
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(serialized))
+            if original.len() == serialized.len() =>
+        {
+            for (index, (original, value)) in original.iter().zip(serialized).enumerate() {
+                compare(&format!("{path}[{index}]"), original, value, diffs);
+            }
+        }
+        (Value::Number(original), Value::Number(serialized))
+            if original.as_f64() == serialized.as_f64() => {}
+        // Timestamps are written back with microseconds
+        (Value::String(original), Value::String(serialized))
+            if Timestamp::parse(original).is_ok()
+                && Timestamp::parse(original) == Timestamp::parse(serialized) => {}
+        (original, serialized) if original == serialized => {}
+        (original, serialized) => diffs.push(format!("{path}: {original} became {serialized}")),
+    }
+}
+
+/// Sorts arrays of strings, since ids collected into sets serialize in no particular order.
+fn canonical(value: Value) -> Value {
+    match value {
+        Value::Array(values) => {
+            let mut values = values.into_iter().map(canonical).collect::<Vec<_>>();
+
+            if values.iter().all(Value::is_string) {
+                values.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+            }
+
+            Value::Array(values)
+        }
+        Value::Object(map) => map.into_iter().map(|(k, v)| (k, canonical(v))).collect(),
+        value => value,
+    }
+}
+
+#[test]
+fn interactions() {
+    round_trip::<Interaction>("interaction");
+}
+
+#[test]
+fn messages() {
+    round_trip::<Message>("message");
+}
+
+#[test]
+fn channels() {
+    round_trip::<Channel>("channel");
+}
+
+#[test]
+fn applications() {
+    round_trip::<Application>("application");
+}
+
+#[test]
+fn stickers() {
+    round_trip::<Sticker>("sticker");
+}
+
+#[test]
+fn unknown_values() {
+    let json = fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/payloads/message/poll_result.json"),
+    )
+    .unwrap();
+
+    let message: Message = serde_json::from_str(&json).unwrap();
+    assert_eq!(message.message_type, MessageType::Unknown(46));
+    assert!(matches!(
+        &message.embeds[0].embed_type,
+        Some(EmbedType::Unknown(t)) if t == "poll_result"
+    ));
+
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["type"], 46);
+    assert_eq!(value["embeds"][0]["type"], "poll_result");
+
+    assert_eq!(
+        serde_json::from_str::<ChannelType>("15").unwrap(),
+        ChannelType::Unknown(15)
+    );
+    assert_eq!(serde_json::to_string(&ChannelType::GuildText).unwrap(), "0");
+}
+
+#[test]
+fn focused_options_keep_partial_input() {
+    for (option_type, value) in [(4, "-"), (4, "12"), (10, "1."), (10, "-0.5"), (3, "")] {
+        let json = format!(
+            r#"{{"name":"search","options":[{{"name":"query","type":{option_type},"value":"{value}","focused":true}}]}}"#
+        );
+        let data = serde_json::from_str::<InteractionData>(&json).unwrap();
+
+        let focused = data.focused_option().unwrap();
+        assert_eq!(focused.name, "query");
+        assert_eq!(focused.value, value);
+
+        assert_eq!(
+            data.options[0],
+            ApplicationCommandInteractionDataOption::Focused {
+                name: "query".to_string(),
+                option_type: ApplicationCommandOptionType::from(option_type),
+                value: Some(RawOptionValue::String(value.to_string())),
+            }
+        );
+
+        let option = serde_json::to_value(&data.options[0]).unwrap();
+        assert_eq!(option["type"], option_type);
+        assert_eq!(option["value"], value);
+        assert_eq!(option["focused"], true);
+    }
+
+    // Only the focused option gets its raw input, the rest are still typed
+    let data = serde_json::from_str::<InteractionData>(
+        r#"{"options":[{"name":"limit","type":4,"value":"x","focused":false}]}"#,
+    );
+    assert!(data.is_err());
+}
@@ -0,0 +1,188 @@
+use discordant_types::{Id, Snowflake, SnowflakeError, SnowflakeGenerator, Timestamp, UserMarker};
+
+// 2021-05-25T19:57:05.214Z
+const MAY_25: i64 = 1_621_972_625_214;
+
+#[test]
+fn parts_round_trip() {
+    let id = Snowflake::from_parts(MAY_25, 17, 9, 1234).unwrap();
+
+    assert_eq!(id.timestamp(), MAY_25);
+    assert_eq!(id.worker_id(), 17);
+    assert_eq!(id.process_id(), 9);
+    assert_eq!(id.increment(), 1234);
+    assert_eq!(id.created_at(), Timestamp::from_unix_millis(MAY_25));
+
+    for id in [Snowflake::new(175928847299117063), Snowflake::new(u64::MAX)] {
+        assert_eq!(
+            Snowflake::from_parts(
+                id.timestamp(),
+                id.worker_id(),
+                id.process_id(),
+                id.increment()
+            ),
+            Ok(id)
+        );
+    }
+}
+
+#[test]
+fn documented_example() {
+    // The example from Discord's reference docs
+    let id = Snowflake::new(175928847299117063);
+
+    assert_eq!(id.timestamp(), 1462015105796);
+    assert_eq!(id.worker_id(), 1);
+    assert_eq!(id.process_id(), 0);
+    assert_eq!(id.increment(), 7);
+    assert_eq!(
+        id.created_at().to_string(),
+        "2016-04-30T11:18:25.796000+00:00"
+    );
+}
+
+#[test]
+fn from_timestamp() {
+    let id = Snowflake::from_timestamp(MAY_25).unwrap();
+
+    assert_eq!(id.timestamp(), MAY_25);
+    assert_eq!((id.worker_id(), id.process_id(), id.increment()), (0, 0, 0));
+    assert!(id < Snowflake::from_parts(MAY_25, 0, 0, 1).unwrap());
+    assert!(id > Snowflake::from_parts(MAY_25 - 1, 31, 31, 4095).unwrap());
+
+    assert_eq!(
+        Snowflake::from_timestamp(Snowflake::DISCORD_EPOCH),
+        Ok(Snowflake::new(0))
+    );
+}
+
+#[test]
+fn parts_out_of_range() {
+    let epoch = Snowflake::DISCORD_EPOCH;
+    let last = epoch + (1 << 42) - 1;
+
+    assert!(Snowflake::from_parts(last, 31, 31, 4095).is_ok());
+    assert_eq!(
+        Snowflake::from_parts(epoch - 1, 0, 0, 0),
+        Err(SnowflakeError::TimestampOutOfRange)
+    );
+    assert_eq!(
+        Snowflake::from_parts(last + 1, 0, 0, 0),
+        Err(SnowflakeError::TimestampOutOfRange)
+    );
+    assert_eq!(
+        Snowflake::from_parts(i64::MIN, 0, 0, 0),
+        Err(SnowflakeError::TimestampOutOfRange)
+    );
+    assert_eq!(
+        Snowflake::from_parts(MAY_25, 32, 0, 0),
+        Err(SnowflakeError::WorkerIdOutOfRange)
+    );
+    assert_eq!(
+        Snowflake::from_parts(MAY_25, 0, 32, 0),
+        Err(SnowflakeError::ProcessIdOutOfRange)
+    );
+    assert_eq!(
+        Snowflake::from_parts(MAY_25, 0, 0, 4096),
+        Err(SnowflakeError::IncrementOutOfRange)
+    );
+}
+
+#[test]
+fn generator() {
+    let generator = SnowflakeGenerator::new(3, 4).unwrap();
+
+    let first = generator.generate(MAY_25).unwrap();
+    let second = generator.generate(MAY_25).unwrap();
+
+    assert_eq!(first.timestamp(), MAY_25);
+    assert_eq!((first.worker_id(), first.process_id()), (3, 4));
+    assert_eq!(first.increment(), 0);
+    assert_eq!(second.increment(), 1);
+    assert!(first < second);
+
+    assert_eq!(
+        generator.generate(Snowflake::DISCORD_EPOCH - 1),
+        Err(SnowflakeError::TimestampOutOfRange)
+    );
+    assert!(generator.generate_now().is_ok());
+
+    assert_eq!(
+        SnowflakeGenerator::new(32, 0).unwrap_err(),
+        SnowflakeError::WorkerIdOutOfRange
+    );
+    assert_eq!(
+        SnowflakeGenerator::new(0, 32).unwrap_err(),
+        SnowflakeError::ProcessIdOutOfRange
+    );
+}
+
+#[test]
+fn generator_increment_wraps() {
+    let generator = SnowflakeGenerator::new(0, 0).unwrap();
+
+    for expected in 0..=4095 {
+        assert_eq!(generator.generate(MAY_25).unwrap().increment(), expected);
+    }
+
+    assert_eq!(generator.generate(MAY_25).unwrap().increment(), 0);
+    assert_eq!(generator.generate(MAY_25).unwrap().increment(), 1);
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        "175928847299117063".parse(),
+        Ok(Snowflake::new(175928847299117063))
+    );
+    assert_eq!(
+        Snowflake::try_from("18446744073709551615"),
+        Ok(Snowflake::new(u64::MAX))
+    );
+
+    for value in ["", "-1", "abc", "18446744073709551616", " 1"] {
+        assert!(
+            matches!(value.parse::<Snowflake>(), Err(SnowflakeError::Parse(_))),
+            "`{value}` should not parse"
+        );
+    }
+}
+
+#[test]
+fn json() {
+    let id = Snowflake::new(175928847299117063);
+
+    assert_eq!(
+        serde_json::to_string(&id).unwrap(),
+        r#""175928847299117063""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Snowflake>(r#""175928847299117063""#).unwrap(),
+        id
+    );
+    assert_eq!(
+        serde_json::from_str::<Snowflake>("175928847299117063").unwrap(),
+        id
+    );
+    assert_eq!(
+        serde_json::from_str::<Snowflake>("18446744073709551615").unwrap(),
+        Snowflake::new(u64::MAX)
+    );
+
+    for json in ["-1", r#""-1""#, r#""abc""#, "1.5", "null", "true"] {
+        assert!(
+            serde_json::from_str::<Snowflake>(json).is_err(),
+            "`{json}` should not deserialize"
+        );
+    }
+
+    let user_id = serde_json::from_str::<Id<UserMarker>>("80351110224678912").unwrap();
+    assert_eq!(
+        user_id,
+        serde_json::from_str::<Id<UserMarker>>(r#""80351110224678912""#).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_string(&user_id).unwrap(),
+        r#""80351110224678912""#
+    );
+}
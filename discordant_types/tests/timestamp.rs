@@ -0,0 +1,224 @@
+use std::time::{Duration, SystemTime};
+
+use discordant_types::Timestamp;
+
+const MAY_25: i64 = 1_621_972_625_214_000;
+
+fn parse(value: &str) -> Timestamp {
+    Timestamp::parse(value).unwrap_or_else(|_| panic!("`{value}` should parse"))
+}
+
+#[test]
+fn parse_fraction() {
+    assert_eq!(
+        parse("2021-05-25T19:57:05.214000+00:00").unix_micros(),
+        MAY_25
+    );
+    assert_eq!(parse("2021-05-25T19:57:05.214+00:00").unix_micros(), MAY_25);
+    assert_eq!(
+        parse("2021-05-25T19:57:05+00:00").unix_micros(),
+        MAY_25 - 214_000
+    );
+
+    // Digits past microseconds are truncated
+    assert_eq!(
+        parse("2021-05-25T19:57:05.214000999+00:00").unix_micros(),
+        MAY_25
+    );
+}
+
+#[test]
+fn parse_offsets() {
+    let utc = parse("2021-05-25T19:57:05.214+00:00");
+
+    assert_eq!(parse("2021-05-25T19:57:05.214Z"), utc);
+    assert_eq!(parse("2021-05-25T19:57:05.214z"), utc);
+    assert_eq!(parse("2021-05-25T21:57:05.214+02:00"), utc);
+    assert_eq!(parse("2021-05-25T14:27:05.214-05:30"), utc);
+
+    // Crossing into the previous day
+    assert_eq!(parse("2021-05-26T01:57:05.214+06:00"), utc);
+}
+
+#[test]
+fn parse_leap_days() {
+    assert_eq!(
+        parse("2024-02-29T12:00:00Z").unix_micros(),
+        1_709_208_000_000_000
+    );
+    assert!(Timestamp::parse("2000-02-29T00:00:00Z").is_ok());
+    assert!(Timestamp::parse("2023-02-29T00:00:00Z").is_err());
+    assert!(Timestamp::parse("1900-02-29T00:00:00Z").is_err());
+}
+
+#[test]
+fn parse_invalid() {
+    for value in [
+        "",
+        "2021-05-25",
+        "2021-05-25T19:57:05",
+        "2021-05-25T19:57:05.+00:00",
+        "2021-05-25T19:57:05.214",
+        "2021-05-25T19:57:05.214+0000",
+        "2021-05-25T19:57:05.214+24:00",
+        "2021-05-25T19:57:05.214+00:00 ",
+        "2021-13-25T19:57:05Z",
+        "2021-00-25T19:57:05Z",
+        "2021-04-31T19:57:05Z",
+        "2021-05-00T19:57:05Z",
+        "2021-05-25T24:00:00Z",
+        "2021-05-25T19:60:00Z",
+        "2021-05-25T19:57:60Z",
+        "21-05-25T19:57:05Z",
+        "2021-5-25T19:57:05Z",
+        "+021-05-25T19:57:05Z",
+    ] {
+        assert!(
+            Timestamp::parse(value).is_err(),
+            "`{value}` should not parse"
+        );
+    }
+}
+
+#[test]
+fn pre_epoch() {
+    let before = parse("1969-12-31T23:59:59.999999Z");
+
+    assert_eq!(before.unix_micros(), -1);
+    assert_eq!(before.to_string(), "1969-12-31T23:59:59.999999+00:00");
+
+    let nineteen_hundred = parse("1900-01-01T00:00:00Z");
+
+    assert_eq!(nineteen_hundred.unix_micros(), -2_208_988_800_000_000);
+    assert_eq!(
+        nineteen_hundred.to_string(),
+        "1900-01-01T00:00:00.000000+00:00"
+    );
+
+    let year_one = parse("0001-01-01T00:00:00Z");
+
+    assert_eq!(year_one.unix_micros(), -62_135_596_800_000_000);
+    assert_eq!(year_one.to_string(), "0001-01-01T00:00:00.000000+00:00");
+}
+
+#[test]
+fn display_round_trips() {
+    for value in [
+        "2021-05-25T19:57:05.214000+00:00",
+        "2024-02-29T23:59:59.999999+00:00",
+        "1970-01-01T00:00:00.000000+00:00",
+        "1969-07-20T20:17:40.000000+00:00",
+        "9999-12-31T23:59:59.999999+00:00",
+    ] {
+        assert_eq!(parse(value).to_string(), value);
+    }
+}
+
+#[test]
+fn serde_round_trips() {
+    let json = r#""2021-05-25T19:57:05.214000+00:00""#;
+    let timestamp = serde_json::from_str::<Timestamp>(json).unwrap();
+
+    assert_eq!(timestamp.unix_micros(), MAY_25);
+    assert_eq!(serde_json::to_string(&timestamp).unwrap(), json);
+    assert!(serde_json::from_str::<Timestamp>(r#""2023-02-29T00:00:00Z""#).is_err());
+}
+
+#[test]
+fn arithmetic() {
+    let timestamp = Timestamp::from_unix_millis(1_000);
+
+    assert_eq!(timestamp.unix_micros(), 1_000_000);
+    assert_eq!(
+        timestamp
+            .saturating_add(Duration::from_millis(500))
+            .unix_millis(),
+        1_500
+    );
+    assert_eq!(
+        timestamp
+            .saturating_sub(Duration::from_secs(2))
+            .unix_millis(),
+        -1_000
+    );
+    assert_eq!(
+        timestamp.duration_since(Timestamp::UNIX_EPOCH),
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(Timestamp::UNIX_EPOCH.duration_since(timestamp), None);
+    assert_eq!(
+        Timestamp::from_unix_micros(i64::MAX)
+            .saturating_add(Duration::from_secs(1))
+            .unix_micros(),
+        i64::MAX
+    );
+}
+
+#[test]
+fn system_time() {
+    for micros in [MAY_25, 0, -1, -2_208_988_800_000_000] {
+        let timestamp = Timestamp::from_unix_micros(micros);
+        let system_time = SystemTime::from(timestamp);
+
+        assert_eq!(Timestamp::from(system_time), timestamp);
+    }
+
+    assert_eq!(
+        SystemTime::from(Timestamp::from_unix_millis(1_500)),
+        SystemTime::UNIX_EPOCH + Duration::from_millis(1_500)
+    );
+    assert_eq!(
+        SystemTime::from(Timestamp::from_unix_millis(-1_500)),
+        SystemTime::UNIX_EPOCH - Duration::from_millis(1_500)
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+    for micros in [MAY_25, 0, -1, -62_135_596_800_000_000] {
+        let timestamp = Timestamp::from_unix_micros(micros);
+        let date_time = DateTime::<Utc>::from(timestamp);
+
+        assert_eq!(date_time.timestamp_micros(), micros);
+        assert_eq!(Timestamp::from(date_time), timestamp);
+    }
+
+    let offset = FixedOffset::east_opt(2 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2021, 5, 25, 21, 57, 5)
+        .unwrap();
+
+    assert_eq!(Timestamp::from(offset), parse("2021-05-25T19:57:05Z"));
+
+    // Saturates outside of chrono's range
+    assert_eq!(
+        DateTime::<Utc>::from(Timestamp::from_unix_micros(i64::MIN)),
+        DateTime::<Utc>::MIN_UTC
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time() {
+    use time::{Month, OffsetDateTime, UtcOffset};
+
+    for micros in [MAY_25, 0, -1, -62_135_596_800_000_000] {
+        let timestamp = Timestamp::from_unix_micros(micros);
+        let date_time = OffsetDateTime::from(timestamp);
+
+        assert_eq!(date_time.unix_timestamp_nanos(), i128::from(micros) * 1_000);
+        assert_eq!(Timestamp::from(date_time), timestamp);
+    }
+
+    let date_time = OffsetDateTime::from(parse("2024-02-29T12:00:00Z"));
+
+    assert_eq!(date_time.month(), Month::February);
+    assert_eq!(date_time.day(), 29);
+
+    let offset = date_time.to_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+
+    assert_eq!(Timestamp::from(offset), parse("2024-02-29T12:00:00Z"));
+}
@@ -0,0 +1,67 @@
+use std::{borrow::Cow, fmt::Write};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use discordant_util::{discord_verify, DiscordState, State, Verifier};
+use ed25519_dalek::{Signer, SigningKey};
+use http::{HeaderMap, HeaderValue};
+
+const BODY: &str = r#"{"application_id":"775799577604522054","channel_id":"772908445358620702","data":{"id":"866818195033292851","name":"context-menu-message-2","resolved":{},"target_id":"867794291820986368","type":3},"guild_id":"772904309264089089","id":"867794297185796116","locale":"en-US","member":{"avatar":null,"deaf":false,"joined_at":"2020-11-02T19:25:47.248000+00:00","mute":false,"nick":"Hello","permissions":"17179869183","roles":["785609923542777878"],"user":{"avatar":"a_f03401914fb4f3caa9037578ab980920","discriminator":"6538","id":"167348773423415296","public_flags":1,"username":"ian"}},"token":"UNIQUE_TOKEN","type":2,"version":1}"#;
+const TIMESTAMP: &str = "1700000000";
+
+/// Leaves `verifiers` empty, so the key is decoded on every request.
+struct KeyState {
+    public_key: String,
+}
+
+impl DiscordState<'static> for KeyState {
+    fn public_key(&self) -> Cow<'static, str> {
+        self.public_key.clone().into()
+    }
+
+    fn application_id(&self) -> Cow<'static, str> {
+        "775799577604522054".into()
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+fn verify(c: &mut Criterion) {
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let public_key = hex(signing_key.verifying_key().as_bytes());
+    let signature = signing_key.sign(format!("{TIMESTAMP}{BODY}").as_bytes());
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-signature-timestamp", HeaderValue::from_static(TIMESTAMP));
+    headers.insert(
+        "x-signature-ed25519",
+        HeaderValue::from_str(&hex(&signature.to_bytes())).unwrap(),
+    );
+
+    let key_state = KeyState {
+        public_key: public_key.clone(),
+    };
+    let state = State::new(public_key.clone(), "775799577604522054").unwrap();
+    let verifier = Verifier::new(&public_key).unwrap();
+
+    let mut group = c.benchmark_group("verify");
+
+    group.bench_function("discord_verify_decoding_key", |b| {
+        b.iter(|| discord_verify(&key_state, black_box(BODY.as_bytes()), &headers))
+    });
+    group.bench_function("discord_verify", |b| {
+        b.iter(|| discord_verify(&state, black_box(BODY.as_bytes()), &headers))
+    });
+    group.bench_function("verifier", |b| {
+        b.iter(|| verifier.verify(black_box(&headers), black_box(BODY.as_bytes())))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, verify);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use discordant_util::{discord_verify, verify_signature, State, Verifier};
+use http::{HeaderMap, HeaderValue};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (&str, &[u8], &[u8], &str)| {
+    let (public_key, timestamp, signature, body) = input;
+
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_bytes(timestamp) {
+        headers.insert("x-signature-timestamp", value);
+    }
+    if let Ok(value) = HeaderValue::from_bytes(signature) {
+        headers.insert("x-signature-ed25519", value);
+    }
+
+    if let Ok(verifier) = Verifier::new(public_key) {
+        let _ = verifier.verify(&headers, body.as_bytes());
+    }
+
+    let map = headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+        .collect::<HashMap<_, _>>();
+    let _ = verify_signature(&map, body, public_key);
+
+    if let Ok(state) = State::new(public_key, "") {
+        let _ = discord_verify(&state, body.as_bytes(), &headers);
+    }
+});
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CustomIdParams(HashMap<String, String>);
+
+impl CustomIdParams {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Segment {
+    Literal(String),
+    Param(String),
+}
+
+/// Matches a component `custom_id` against a pattern such as `vote:{poll_id}:{choice}`.
+///
+/// Each `{name}` captures a non-empty run of characters up to the next literal
+/// segment, or to the end of the `custom_id` if it is the last segment. A `{` without a closing
+/// `}` is matched literally.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CustomIdPattern {
+    segments: Vec<Segment>,
+    prefix: bool,
+}
+
+impl CustomIdPattern {
+    /// Fails if two params follow each other, as in `{a}{b}`, since there is no literal to split
+    /// them on.
+    pub fn new(pattern: &str) -> Result<Self, CustomIdPatternError> {
+        let mut segments = Vec::new();
+        let mut rest = pattern;
+
+        while !rest.is_empty() {
+            let param = rest
+                .find('{')
+                .and_then(|start| Some((start, start + rest[start..].find('}')?)));
+
+            match param {
+                Some((start, end)) => {
+                    if start > 0 {
+                        segments.push(Segment::Literal(rest[..start].to_string()));
+                    }
+                    let name = rest[start + 1..end].to_string();
+
+                    if let Some(Segment::Param(previous)) = segments.last() {
+                        return Err(CustomIdPatternError::AdjacentParams(previous.clone(), name));
+                    }
+
+                    segments.push(Segment::Param(name));
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    segments.push(Segment::Literal(rest.to_string()));
+                    rest = "";
+                }
+            }
+        }
+
+        Ok(Self {
+            segments,
+            prefix: false,
+        })
+    }
+
+    pub fn prefix(prefix: &str) -> Self {
+        Self {
+            segments: vec![Segment::Literal(prefix.to_string())],
+            prefix: true,
+        }
+    }
+
+    pub fn matches(&self, custom_id: &str) -> Option<CustomIdParams> {
+        let mut params = HashMap::new();
+        let mut rest = custom_id;
+        let mut segments = self.segments.iter().peekable();
+
+        while let Some(segment) = segments.next() {
+            match segment {
+                Segment::Literal(literal) => {
+                    rest = rest.strip_prefix(literal.as_str())?;
+                }
+                Segment::Param(name) => {
+                    let end = match segments.peek() {
+                        Some(Segment::Literal(next)) => rest.find(next.as_str())?,
+                        _ => rest.len(),
+                    };
+
+                    if end == 0 {
+                        return None;
+                    }
+
+                    params.insert(name.clone(), rest[..end].to_string());
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        if !self.prefix && !rest.is_empty() {
+            return None;
+        }
+
+        Some(CustomIdParams(params))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CustomIdPatternError {
+    AdjacentParams(String, String),
+}
+
+impl std::fmt::Display for CustomIdPatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomIdPatternError::AdjacentParams(first, second) => write!(
+                f,
+                "params `{{{first}}}` and `{{{second}}}` need a literal between them"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CustomIdPatternError {}
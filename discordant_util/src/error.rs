@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use discordant_types::{
+    InteractionCallbackData, InteractionCallbackDataFlags, InteractionResponse, OptionError,
+};
+use http::StatusCode;
+
+use crate::{InvalidReason, VerifyError};
+
+#[derive(Debug)]
+pub enum DiscordError {
+    InvalidSignature(InvalidReason),
+    /// A stale or repeated request rejected by the handler's `ReplayGuard`.
+    Replay(VerifyError),
+    Decode {
+        path: String,
+        message: String,
+    },
+    MissingData(&'static str),
+    UnknownCommand(String),
+    UnknownComponent(String),
+    /// An autocomplete request for an option without a registered handler.
+    UnknownAutocomplete {
+        command: String,
+        option: String,
+    },
+    UnknownInteractionType(u8),
+    Options(OptionError),
+    /// An error meant to be shown to the user who triggered the interaction.
+    User(String),
+    Handler(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl DiscordError {
+    pub fn user<T>(message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::User(message.into())
+    }
+
+    pub fn handler<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::Handler(error.into())
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            DiscordError::InvalidSignature(_) | DiscordError::Replay(_) => StatusCode::UNAUTHORIZED,
+            DiscordError::Decode { .. }
+            | DiscordError::MissingData(_)
+            | DiscordError::Options(_)
+            | DiscordError::User(_) => StatusCode::BAD_REQUEST,
+            DiscordError::UnknownCommand(_)
+            | DiscordError::UnknownComponent(_)
+            | DiscordError::UnknownAutocomplete { .. }
+            | DiscordError::UnknownInteractionType(_) => StatusCode::NOT_FOUND,
+            DiscordError::Handler(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn is_user_facing(&self) -> bool {
+        matches!(self, DiscordError::User(_) | DiscordError::Options(_))
+    }
+}
+
+impl Display for DiscordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscordError::InvalidSignature(reason) => {
+                write!(f, "invalid request signature: {reason}")
+            }
+            DiscordError::Replay(e) => write!(f, "rejected request: {e}"),
+            DiscordError::Decode { path, message } => {
+                write!(f, "could not decode interaction at `{path}`: {message}")
+            }
+            DiscordError::MissingData(field) => write!(f, "interaction is missing `{field}`"),
+            DiscordError::UnknownCommand(name) => write!(f, "unknown command `{name}`"),
+            DiscordError::UnknownComponent(custom_id) => {
+                write!(f, "unknown component `{custom_id}`")
+            }
+            DiscordError::UnknownAutocomplete { command, option } => {
+                write!(
+                    f,
+                    "no autocomplete handler for option `{option}` of `{command}`"
+                )
+            }
+            DiscordError::UnknownInteractionType(value) => {
+                write!(f, "unknown interaction type `{value}`")
+            }
+            DiscordError::Options(e) => e.fmt(f),
+            DiscordError::User(message) => write!(f, "{message}"),
+            DiscordError::Handler(e) => write!(f, "handler failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for DiscordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DiscordError::Replay(e) => Some(e),
+            DiscordError::Options(e) => Some(e),
+            DiscordError::Handler(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<OptionError> for DiscordError {
+    fn from(value: OptionError) -> Self {
+        Self::Options(value)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for DiscordError {
+    fn from(value: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self::Decode {
+            path: value.path().to_string(),
+            message: value.inner().to_string(),
+        }
+    }
+}
+
+impl From<DiscordError> for StatusCode {
+    fn from(value: DiscordError) -> Self {
+        value.status_code()
+    }
+}
+
+/// An error hook for `DiscordHandler::on_error` that answers user-facing errors with an
+/// ephemeral message, leaving every other error to fail the interaction.
+pub fn ephemeral_user_errors(error: &DiscordError) -> Option<InteractionResponse> {
+    if !error.is_user_facing() {
+        return None;
+    }
+
+    Some(
+        InteractionResponse::new().data(
+            InteractionCallbackData::new()
+                .content(error.to_string())
+                .flags(InteractionCallbackDataFlags::Ephemeral),
+        ),
+    )
+}
@@ -0,0 +1,807 @@
+use discordant_types::{
+    ApplicationCommandOptionType, ApplicationCommandType, ApplicationMarker, AttachmentMarker,
+    ChannelMarker, ChannelType, CommandMarker, ComponentType, GuildMarker, Id, Interaction,
+    InteractionMarker, InteractionType, MessageMarker, RoleMarker, UserMarker,
+};
+use serde_json::{json, Map, Value};
+
+pub const APPLICATION_ID: Id<ApplicationMarker> = Id::new(775799577604522054);
+pub const GUILD_ID: Id<GuildMarker> = Id::new(772904309264089089);
+pub const CHANNEL_ID: Id<ChannelMarker> = Id::new(772908445358620702);
+pub const INTERACTION_ID: Id<InteractionMarker> = Id::new(867794297185796116);
+pub const COMMAND_ID: Id<CommandMarker> = Id::new(866818195033292851);
+pub const MESSAGE_ID: Id<MessageMarker> = Id::new(867793854505943041);
+pub const USER_ID: Id<UserMarker> = Id::new(167348773423415296);
+pub const TOKEN: &str = "UNIQUE_TOKEN";
+
+const JOINED_AT: &str = "2020-11-02T19:25:47.248000+00:00";
+const PERMISSIONS: &str = "2147483647";
+
+#[derive(Debug, Clone)]
+pub struct UserFixture {
+    id: Id<UserMarker>,
+    username: String,
+    global_name: Option<String>,
+    avatar: Option<String>,
+    bot: bool,
+}
+
+impl UserFixture {
+    pub fn new<T>(id: Id<UserMarker>, username: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            id,
+            username: username.into(),
+            global_name: None,
+            avatar: None,
+            bot: false,
+        }
+    }
+
+    pub fn global_name<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.global_name = Some(value.into());
+        self
+    }
+
+    pub fn avatar<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.avatar = Some(value.into());
+        self
+    }
+
+    pub fn bot(mut self, value: bool) -> Self {
+        self.bot = value;
+        self
+    }
+
+    fn to_value(&self) -> Value {
+        let mut user = json!({
+            "id": self.id,
+            "username": self.username,
+            "discriminator": "0",
+            "global_name": self.global_name,
+            "avatar": self.avatar,
+            "public_flags": 0,
+        });
+
+        if self.bot {
+            user["bot"] = json!(true);
+        }
+
+        user
+    }
+}
+
+impl Default for UserFixture {
+    fn default() -> Self {
+        Self::new(USER_ID, "tester")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemberFixture {
+    user: UserFixture,
+    nick: Option<String>,
+    roles: Vec<Id<RoleMarker>>,
+    permissions: Option<String>,
+    communication_disabled_until: Option<String>,
+}
+
+impl MemberFixture {
+    pub fn new(user: UserFixture) -> Self {
+        Self {
+            user,
+            ..Default::default()
+        }
+    }
+
+    pub fn nick<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.nick = Some(value.into());
+        self
+    }
+
+    pub fn role(mut self, id: Id<RoleMarker>) -> Self {
+        self.roles.push(id);
+        self
+    }
+
+    /// The member's permissions in the interaction's channel, as a decimal string.
+    pub fn permissions<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.permissions = Some(value.into());
+        self
+    }
+
+    pub fn communication_disabled_until<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.communication_disabled_until = Some(value.into());
+        self
+    }
+
+    /// The member as it appears in `resolved.members`, which leaves out `user`.
+    fn to_partial_value(&self) -> Value {
+        json!({
+            "nick": self.nick,
+            "avatar": null,
+            "roles": self.roles,
+            "joined_at": JOINED_AT,
+            "premium_since": null,
+            "flags": 0,
+            "pending": false,
+            "permissions": self.permissions.as_deref().unwrap_or(PERMISSIONS),
+            "communication_disabled_until": self.communication_disabled_until,
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        let mut member = self.to_partial_value();
+        member["user"] = self.user.to_value();
+        member["deaf"] = json!(false);
+        member["mute"] = json!(false);
+        member
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OptionValue {
+    Value(Value),
+    Options(Vec<OptionFixture>),
+}
+
+/// An option sent with a slash command or autocomplete interaction.
+#[derive(Debug, Clone)]
+pub struct OptionFixture {
+    name: String,
+    option_type: ApplicationCommandOptionType,
+    value: OptionValue,
+    focused: bool,
+    resolved: Vec<(&'static str, String, Value)>,
+}
+
+impl OptionFixture {
+    fn new<T>(name: T, option_type: ApplicationCommandOptionType, value: OptionValue) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            option_type,
+            value,
+            focused: false,
+            resolved: Vec::new(),
+        }
+    }
+
+    pub fn subcommand<T, I>(name: T, options: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = OptionFixture>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::SubCommand,
+            OptionValue::Options(options.into_iter().collect()),
+        )
+    }
+
+    pub fn group<T, I>(name: T, subcommands: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = OptionFixture>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::SubCommandGroup,
+            OptionValue::Options(subcommands.into_iter().collect()),
+        )
+    }
+
+    pub fn string<T, U>(name: T, value: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::String,
+            OptionValue::Value(json!(value.into())),
+        )
+    }
+
+    pub fn integer<T>(name: T, value: i64) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::Integer,
+            OptionValue::Value(json!(value)),
+        )
+    }
+
+    pub fn boolean<T>(name: T, value: bool) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::Boolean,
+            OptionValue::Value(json!(value)),
+        )
+    }
+
+    /// A user option, resolved to a user without guild membership.
+    pub fn user<T>(name: T, user: UserFixture) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut option = Self::new(
+            name,
+            ApplicationCommandOptionType::User,
+            OptionValue::Value(json!(user.id)),
+        );
+        option.resolve_user(&user);
+        option
+    }
+
+    /// A user option, resolved to both the user and their guild membership.
+    pub fn member<T>(name: T, member: MemberFixture) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut option = Self::new(
+            name,
+            ApplicationCommandOptionType::User,
+            OptionValue::Value(json!(member.user.id)),
+        );
+        option.resolve_member(&member);
+        option
+    }
+
+    pub fn channel<T, U>(
+        name: T,
+        id: Id<ChannelMarker>,
+        channel_name: U,
+        channel_type: ChannelType,
+    ) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let channel = json!({
+            "id": id,
+            "name": channel_name.into(),
+            "type": channel_type,
+            "permissions": PERMISSIONS,
+        });
+
+        let mut option = Self::new(
+            name,
+            ApplicationCommandOptionType::Channel,
+            OptionValue::Value(json!(id)),
+        );
+        option.resolved.push(("channels", id.to_string(), channel));
+        option
+    }
+
+    pub fn role<T, U>(name: T, id: Id<RoleMarker>, role_name: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let role = role_value(id, role_name.into());
+
+        let mut option = Self::new(
+            name,
+            ApplicationCommandOptionType::Role,
+            OptionValue::Value(json!(id)),
+        );
+        option.resolved.push(("roles", id.to_string(), role));
+        option
+    }
+
+    /// A mentionable option that mentions a role.
+    pub fn mentionable_role<T, U>(name: T, id: Id<RoleMarker>, role_name: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut option = Self::role(name, id, role_name);
+        option.option_type = ApplicationCommandOptionType::Mentionable;
+        option
+    }
+
+    /// A mentionable option that mentions a user.
+    pub fn mentionable_user<T>(name: T, user: UserFixture) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut option = Self::user(name, user);
+        option.option_type = ApplicationCommandOptionType::Mentionable;
+        option
+    }
+
+    pub fn number<T>(name: T, value: f64) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            name,
+            ApplicationCommandOptionType::Number,
+            OptionValue::Value(json!(value)),
+        )
+    }
+
+    pub fn attachment<T, U>(name: T, id: Id<AttachmentMarker>, filename: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let filename = filename.into();
+        let url = format!(
+            "https://cdn.discordapp.com/ephemeral-attachments/{CHANNEL_ID}/{id}/{filename}"
+        );
+        let attachment = json!({
+            "id": id,
+            "filename": filename,
+            "size": 1024,
+            "url": url,
+            "proxy_url": url.replace("cdn.discordapp.com", "media.discordapp.net"),
+            "ephemeral": true,
+        });
+
+        let mut option = Self::new(
+            name,
+            ApplicationCommandOptionType::Attachment,
+            OptionValue::Value(json!(id)),
+        );
+        option
+            .resolved
+            .push(("attachments", id.to_string(), attachment));
+        option
+    }
+
+    /// Marks the option as the one being typed in an autocomplete interaction.
+    pub fn focused(mut self) -> Self {
+        self.focused = true;
+        self
+    }
+
+    fn resolve_user(&mut self, user: &UserFixture) {
+        self.resolved
+            .push(("users", user.id.to_string(), user.to_value()));
+    }
+
+    fn resolve_member(&mut self, member: &MemberFixture) {
+        self.resolve_user(&member.user);
+        self.resolved.push((
+            "members",
+            member.user.id.to_string(),
+            member.to_partial_value(),
+        ));
+    }
+
+    fn to_value(&self, resolved: &mut Resolved) -> Value {
+        for (kind, id, value) in &self.resolved {
+            resolved.insert(kind, id, value.clone());
+        }
+
+        let mut option = json!({
+            "name": self.name,
+            "type": self.option_type,
+        });
+
+        match &self.value {
+            OptionValue::Value(value) => option["value"] = value.clone(),
+            OptionValue::Options(options) => {
+                option["options"] = options.iter().map(|o| o.to_value(resolved)).collect();
+            }
+        }
+
+        if self.focused {
+            option["focused"] = json!(true);
+        }
+
+        option
+    }
+}
+
+#[derive(Debug, Default)]
+struct Resolved(Map<String, Value>);
+
+impl Resolved {
+    fn insert(&mut self, kind: &str, id: &str, value: Value) {
+        let entries = self
+            .0
+            .entry(kind)
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        entries[id] = value;
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Context {
+    Guild(MemberFixture),
+    Dm(UserFixture),
+}
+
+/// Builds an `Interaction` the way Discord sends it to an interactions endpoint.
+///
+/// Interactions happen in a guild, invoked by a default member, unless `member` or `dm` says
+/// otherwise.
+#[derive(Debug, Clone)]
+pub struct InteractionBuilder {
+    id: Id<InteractionMarker>,
+    application_id: Id<ApplicationMarker>,
+    interaction_type: InteractionType,
+    data: Option<Value>,
+    options: Vec<OptionFixture>,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    context: Context,
+    message: Option<Value>,
+    token: String,
+    locale: String,
+    guild_locale: String,
+    app_permissions: String,
+}
+
+impl InteractionBuilder {
+    fn new(interaction_type: InteractionType, data: Option<Value>) -> Self {
+        Self {
+            id: INTERACTION_ID,
+            application_id: APPLICATION_ID,
+            interaction_type,
+            data,
+            options: Vec::new(),
+            guild_id: GUILD_ID,
+            channel_id: CHANNEL_ID,
+            context: Context::Guild(MemberFixture::default()),
+            message: None,
+            token: TOKEN.to_string(),
+            locale: "en-US".to_string(),
+            guild_locale: "en-US".to_string(),
+            app_permissions: PERMISSIONS.to_string(),
+        }
+    }
+
+    pub fn ping() -> Self {
+        Self::new(InteractionType::Ping, None)
+    }
+
+    pub fn slash_command<T>(name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            InteractionType::ApplicationCommand,
+            Some(command_data(name.into(), ApplicationCommandType::ChatInput)),
+        )
+    }
+
+    /// A user context menu command run on `target`.
+    pub fn user_command<T>(name: T, target: MemberFixture) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut data = command_data(name.into(), ApplicationCommandType::User);
+        let id = target.user.id.to_string();
+        let mut resolved = Resolved::default();
+        resolved.insert("users", &id, target.user.to_value());
+        resolved.insert("members", &id, target.to_partial_value());
+
+        data["target_id"] = json!(target.user.id);
+        data["resolved"] = Value::Object(resolved.0);
+
+        Self::new(InteractionType::ApplicationCommand, Some(data))
+    }
+
+    /// A message context menu command run on a message by `author`.
+    pub fn message_command<T, U>(
+        name: T,
+        message_id: Id<MessageMarker>,
+        author: UserFixture,
+        content: U,
+    ) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let message = message_value(message_id, CHANNEL_ID, &author, content.into());
+
+        let mut data = command_data(name.into(), ApplicationCommandType::Message);
+        let mut resolved = Resolved::default();
+        resolved.insert("messages", &message_id.to_string(), message);
+
+        data["target_id"] = json!(message_id);
+        data["resolved"] = Value::Object(resolved.0);
+
+        Self::new(InteractionType::ApplicationCommand, Some(data))
+    }
+
+    /// A click on the button `custom_id`, attached to a message the application sent.
+    pub fn button<T>(custom_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::component(custom_id.into(), ComponentType::Button, Vec::new())
+    }
+
+    pub fn select<T, I, V>(custom_id: T, values: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let values = values.into_iter().map(Into::into).collect();
+        Self::component(custom_id.into(), ComponentType::SelectMenu, values)
+    }
+
+    fn component(custom_id: String, component_type: ComponentType, values: Vec<String>) -> Self {
+        let mut data = json!({
+            "custom_id": custom_id,
+            "component_type": component_type,
+        });
+
+        if component_type == ComponentType::SelectMenu {
+            data["values"] = json!(values);
+        }
+
+        let bot = UserFixture::new(APPLICATION_ID.cast(), "bot").bot(true);
+        let mut message = message_value(MESSAGE_ID, CHANNEL_ID, &bot, String::new());
+        message["application_id"] = json!(APPLICATION_ID);
+
+        let mut builder = Self::new(InteractionType::MessageComponent, Some(data));
+        builder.message = Some(message);
+        builder
+    }
+
+    /// The submission of the modal `custom_id`. Add its fields with `text_input`.
+    pub fn modal_submit<T>(custom_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            InteractionType::ModalSubmit,
+            Some(json!({
+                "custom_id": custom_id.into(),
+                "components": [],
+            })),
+        )
+    }
+
+    /// An autocomplete request for `name`. One of its options should be `focused`.
+    pub fn autocomplete<T>(name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            InteractionType::ApplicationCommandAutocomplete,
+            Some(command_data(name.into(), ApplicationCommandType::ChatInput)),
+        )
+    }
+
+    pub fn id(mut self, value: Id<InteractionMarker>) -> Self {
+        self.id = value;
+        self
+    }
+
+    pub fn application_id(mut self, value: Id<ApplicationMarker>) -> Self {
+        self.application_id = value;
+        self
+    }
+
+    pub fn command_id(mut self, value: Id<CommandMarker>) -> Self {
+        if let Some(data) = &mut self.data {
+            if data.get("id").is_some() {
+                data["id"] = json!(value);
+            }
+        }
+
+        self
+    }
+
+    pub fn guild_id(mut self, value: Id<GuildMarker>) -> Self {
+        self.guild_id = value;
+        self
+    }
+
+    pub fn channel_id(mut self, value: Id<ChannelMarker>) -> Self {
+        self.channel_id = value;
+        self
+    }
+
+    /// The guild member who triggered the interaction.
+    pub fn member(mut self, value: MemberFixture) -> Self {
+        self.context = Context::Guild(value);
+        self
+    }
+
+    /// Moves the interaction into a direct message with `user`.
+    pub fn dm(mut self, user: UserFixture) -> Self {
+        self.context = Context::Dm(user);
+        self
+    }
+
+    pub fn option(mut self, value: OptionFixture) -> Self {
+        self.options.push(value);
+        self
+    }
+
+    /// Adds a text input to a modal submission, in its own action row.
+    pub fn text_input<T, U>(mut self, custom_id: T, value: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        if let Some(Value::Array(components)) = self
+            .data
+            .as_mut()
+            .and_then(|data| data.get_mut("components"))
+        {
+            components.push(json!({
+                "type": ComponentType::ActionRow,
+                "components": [{
+                    "type": ComponentType::TextInput,
+                    "custom_id": custom_id.into(),
+                    "value": value.into(),
+                }],
+            }));
+        }
+
+        self
+    }
+
+    pub fn token<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.token = value.into();
+        self
+    }
+
+    pub fn locale<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.locale = value.into();
+        self
+    }
+
+    pub fn app_permissions<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.app_permissions = value.into();
+        self
+    }
+
+    pub fn to_value(&self) -> Value {
+        let mut interaction = json!({
+            "id": self.id,
+            "application_id": self.application_id,
+            "type": self.interaction_type,
+            "channel_id": self.channel_id,
+            "token": self.token,
+            "version": 1,
+            "app_permissions": self.app_permissions,
+            "locale": self.locale,
+        });
+
+        match &self.context {
+            Context::Guild(member) => {
+                interaction["guild_id"] = json!(self.guild_id);
+                interaction["guild_locale"] = json!(self.guild_locale);
+                interaction["member"] = member.to_value();
+            }
+            Context::Dm(user) => interaction["user"] = user.to_value(),
+        }
+
+        if let Some(data) = &self.data {
+            let mut data = data.clone();
+
+            if !self.options.is_empty() {
+                // Keep what a context menu command already resolved for its target
+                let mut resolved = match data.get_mut("resolved").map(Value::take) {
+                    Some(Value::Object(entries)) => Resolved(entries),
+                    _ => Resolved::default(),
+                };
+                let options = self
+                    .options
+                    .iter()
+                    .map(|o| o.to_value(&mut resolved))
+                    .collect();
+
+                data["options"] = options;
+
+                if !resolved.0.is_empty() {
+                    data["resolved"] = Value::Object(resolved.0);
+                }
+            }
+
+            interaction["data"] = data;
+        }
+
+        if let Some(message) = &self.message {
+            let mut message = message.clone();
+            message["channel_id"] = json!(self.channel_id);
+            interaction["message"] = message;
+        }
+
+        interaction
+    }
+
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+
+    /// Panics if the JSON does not deserialize, which points at a gap in `discordant_types`.
+    pub fn build(&self) -> Interaction {
+        serde_json::from_value(self.to_value()).expect("fixture is a valid interaction")
+    }
+}
+
+fn command_data(name: String, command_type: ApplicationCommandType) -> Value {
+    json!({
+        "id": COMMAND_ID,
+        "name": name,
+        "type": command_type,
+    })
+}
+
+fn role_value(id: Id<RoleMarker>, name: String) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "color": 0,
+        "hoist": false,
+        "icon": null,
+        "unicode_emoji": null,
+        "position": 1,
+        "permissions": "0",
+        "managed": false,
+        "mentionable": true,
+        "flags": 0,
+    })
+}
+
+fn message_value(
+    id: Id<MessageMarker>,
+    channel_id: Id<ChannelMarker>,
+    author: &UserFixture,
+    content: String,
+) -> Value {
+    json!({
+        "id": id,
+        "channel_id": channel_id,
+        "author": author.to_value(),
+        "content": content,
+        "timestamp": JOINED_AT,
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0,
+        "flags": 0,
+        "components": [],
+    })
+}
@@ -1,15 +1,60 @@
 use std::{borrow::Cow, collections::HashMap, future::Future};
 
 use discordant_types::{
-    ApplicationCommand, Interaction, InteractionCallbackType, InteractionResponse, InteractionType,
+    ApplicationCommand, ApplicationCommandInteractionDataOption, ApplicationCommandOption,
+    ApplicationCommandOptionChoice, ApplicationCommandOptionType, CommandGroup, FocusedOption,
+    Interaction, InteractionCallbackData, InteractionCallbackType, InteractionResponse,
+    InteractionType,
 };
+use futures_util::future;
+#[cfg(feature = "send")]
+use futures_util::future::BoxFuture;
+#[cfg(not(feature = "send"))]
 use futures_util::future::LocalBoxFuture;
-use http::{HeaderMap, StatusCode};
+use http::HeaderMap;
+use tracing::debug;
 
-use crate::{discord_verify, DiscordState, DiscordVerify};
+use crate::{
+    custom_id::{CustomIdParams, CustomIdPattern},
+    discord_verify,
+    error::DiscordError,
+    replay::ReplayGuard,
+    DiscordState, DiscordVerify,
+};
+
+#[cfg(not(feature = "send"))]
+type HandlerFuture<'a, T> = LocalBoxFuture<'a, Result<T, DiscordError>>;
+#[cfg(feature = "send")]
+type HandlerFuture<'a, T> = BoxFuture<'a, Result<T, DiscordError>>;
+
+/// `Send` when the `send` feature is enabled, otherwise implemented for every type.
+#[cfg(not(feature = "send"))]
+pub trait MaybeSend {}
+#[cfg(not(feature = "send"))]
+impl<T: ?Sized> MaybeSend for T {}
+
+#[cfg(feature = "send")]
+pub trait MaybeSend: Send {}
+#[cfg(feature = "send")]
+impl<T: ?Sized + Send> MaybeSend for T {}
 
+/// `Sync` when the `send` feature is enabled, otherwise implemented for every type.
+#[cfg(not(feature = "send"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "send"))]
+impl<T: ?Sized> MaybeSync for T {}
+
+#[cfg(feature = "send")]
+pub trait MaybeSync: Sync {}
+#[cfg(feature = "send")]
+impl<T: ?Sized + Sync> MaybeSync for T {}
+
+#[cfg(not(feature = "send"))]
+type HandleAction<'a, S> =
+    Box<dyn 'a + Fn(S, Interaction) -> HandlerFuture<'a, InteractionResponse>>;
+#[cfg(feature = "send")]
 type HandleAction<'a, S> =
-    Box<dyn 'a + Fn(S, Interaction) -> LocalBoxFuture<'a, Result<InteractionResponse, StatusCode>>>;
+    Box<dyn 'a + Send + Sync + Fn(S, Interaction) -> HandlerFuture<'a, InteractionResponse>>;
 
 pub struct CommandHandler<'a, S>
 where
@@ -23,18 +68,183 @@ impl<'a, S> CommandHandler<'a, S>
 where
     S: DiscordState<'a> + 'a,
 {
-    pub fn new<R>(command: ApplicationCommand<'a>, handler: fn(S, Interaction) -> R) -> Self
+    pub fn new<F, R>(command: ApplicationCommand<'a>, handler: F) -> Self
     where
-        R: Future<Output = Result<InteractionResponse, StatusCode>> + 'a,
+        F: Fn(S, Interaction) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
     {
         Self {
             command,
             handler: Box::new(move |state, interaction| Box::pin(handler(state, interaction))),
         }
     }
+
+    /// Registers a command built from a `CommandGroup`, handing the parsed subcommand to `handler`.
+    pub fn group<T, F, R>(handler: F) -> Self
+    where
+        T: for<'x> CommandGroup<'x> + 'a,
+        F: Fn(S, Interaction, T) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
+    {
+        Self {
+            command: T::command(),
+            handler: Box::new(move |state, interaction| {
+                let parsed = interaction
+                    .data
+                    .as_ref()
+                    .ok_or(DiscordError::MissingData("data"))
+                    .and_then(|data| T::from_data(data).map_err(DiscordError::from));
+
+                let res: HandlerFuture<'a, InteractionResponse> = match parsed {
+                    Ok(value) => Box::pin(handler(state, interaction, value)),
+                    Err(e) => Box::pin(future::ready(Err(e))),
+                };
+
+                res
+            }),
+        }
+    }
+}
+
+/// The full name of a subcommand, such as `("config", "get")` or `("config", "set", "prefix")`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubcommandPath<'a>(Vec<Cow<'a, str>>);
+
+impl<'a> SubcommandPath<'a> {
+    pub fn command(&self) -> &str {
+        &self.0[0]
+    }
+
+    pub fn group(&self) -> Option<&str> {
+        match self.0.as_slice() {
+            [_, group, _] => Some(group),
+            _ => None,
+        }
+    }
+
+    pub fn subcommand(&self) -> &str {
+        &self.0[self.0.len() - 1]
+    }
+}
+
+impl<'a> From<(&'a str, &'a str)> for SubcommandPath<'a> {
+    fn from((command, subcommand): (&'a str, &'a str)) -> Self {
+        Self(vec![command.into(), subcommand.into()])
+    }
+}
+
+impl<'a> From<(&'a str, &'a str, &'a str)> for SubcommandPath<'a> {
+    fn from((command, group, subcommand): (&'a str, &'a str, &'a str)) -> Self {
+        Self(vec![command.into(), group.into(), subcommand.into()])
+    }
+}
+
+impl<'a, 'b> PartialEq<[&'b str]> for SubcommandPath<'a> {
+    fn eq(&self, other: &[&'b str]) -> bool {
+        self.0.iter().map(|x| x.as_ref()).eq(other.iter().copied())
+    }
+}
+
+/// A command or subcommand group that holds subcommands, such as `"config"` or
+/// `("config", "set")`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GroupPath<'a>(Vec<Cow<'a, str>>);
+
+impl<'a> From<&'a str> for GroupPath<'a> {
+    fn from(command: &'a str) -> Self {
+        Self(vec![command.into()])
+    }
+}
+
+impl<'a> From<(&'a str, &'a str)> for GroupPath<'a> {
+    fn from((command, group): (&'a str, &'a str)) -> Self {
+        Self(vec![command.into(), group.into()])
+    }
+}
+
+impl<'a, 'b> PartialEq<[&'b str]> for GroupPath<'a> {
+    fn eq(&self, other: &[&'b str]) -> bool {
+        self.0.iter().map(|x| x.as_ref()).eq(other.iter().copied())
+    }
+}
+
+/// A command or group that only exists through `DiscordHandler::subcommand` and was not given a
+/// description with `DiscordHandler::group`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MissingDescription(pub String);
+
+impl std::fmt::Display for MissingDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` has no description", self.0)
+    }
+}
+
+impl std::error::Error for MissingDescription {}
+
+#[cfg(not(feature = "send"))]
+type SubcommandAction<'a, S> = Box<
+    dyn 'a
+        + Fn(
+            S,
+            Interaction,
+            Vec<ApplicationCommandInteractionDataOption>,
+        ) -> HandlerFuture<'a, InteractionResponse>,
+>;
+#[cfg(feature = "send")]
+type SubcommandAction<'a, S> = Box<
+    dyn 'a
+        + Send
+        + Sync
+        + Fn(
+            S,
+            Interaction,
+            Vec<ApplicationCommandInteractionDataOption>,
+        ) -> HandlerFuture<'a, InteractionResponse>,
+>;
+
+pub struct SubcommandHandler<'a, S>
+where
+    S: DiscordState<'a>,
+{
+    pub option: ApplicationCommandOption<'a>,
+    pub handler: SubcommandAction<'a, S>,
+}
+
+impl<'a, S> SubcommandHandler<'a, S>
+where
+    S: DiscordState<'a> + 'a,
+{
+    /// `option` describes the subcommand. Its name and type are taken from the path it is
+    /// registered at.
+    pub fn new<F, R>(option: ApplicationCommandOption<'a>, handler: F) -> Self
+    where
+        F: Fn(S, Interaction, Vec<ApplicationCommandInteractionDataOption>) -> R
+            + MaybeSend
+            + MaybeSync
+            + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
+    {
+        Self {
+            option,
+            handler: Box::new(move |state, interaction, options| {
+                Box::pin(handler(state, interaction, options))
+            }),
+        }
+    }
 }
 
-pub struct ComponentHandler<'a, S>(HandleAction<'a, S>)
+#[cfg(not(feature = "send"))]
+type ComponentAction<'a, S> =
+    Box<dyn 'a + Fn(S, Interaction, CustomIdParams) -> HandlerFuture<'a, InteractionResponse>>;
+#[cfg(feature = "send")]
+type ComponentAction<'a, S> = Box<
+    dyn 'a
+        + Send
+        + Sync
+        + Fn(S, Interaction, CustomIdParams) -> HandlerFuture<'a, InteractionResponse>,
+>;
+
+pub struct ComponentHandler<'a, S>(ComponentAction<'a, S>)
 where
     S: DiscordState<'a>;
 
@@ -42,9 +252,39 @@ impl<'a, S> ComponentHandler<'a, S>
 where
     S: DiscordState<'a> + 'a,
 {
-    pub fn new<R>(handler: fn(S, Interaction) -> R) -> Self
+    pub fn new<F, R>(handler: F) -> Self
+    where
+        F: Fn(S, Interaction) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
+    {
+        Self(Box::new(move |state, interaction, _| {
+            Box::pin(handler(state, interaction))
+        }))
+    }
+
+    pub fn with_params<F, R>(handler: F) -> Self
     where
-        R: Future<Output = Result<InteractionResponse, StatusCode>> + 'a,
+        F: Fn(S, Interaction, CustomIdParams) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
+    {
+        Self(Box::new(move |state, interaction, params| {
+            Box::pin(handler(state, interaction, params))
+        }))
+    }
+}
+
+pub struct ModalHandler<'a, S>(HandleAction<'a, S>)
+where
+    S: DiscordState<'a>;
+
+impl<'a, S> ModalHandler<'a, S>
+where
+    S: DiscordState<'a> + 'a,
+{
+    pub fn new<F, R>(handler: F) -> Self
+    where
+        F: Fn(S, Interaction) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<InteractionResponse, DiscordError>> + MaybeSend + 'a,
     {
         Self(Box::new(move |state, interaction| {
             Box::pin(handler(state, interaction))
@@ -52,12 +292,73 @@ where
     }
 }
 
+#[cfg(not(feature = "send"))]
+type AutocompleteAction<'a, S> = Box<
+    dyn 'a
+        + Fn(
+            S,
+            Interaction,
+            FocusedOption,
+        ) -> HandlerFuture<'a, Vec<ApplicationCommandOptionChoice<'static>>>,
+>;
+#[cfg(feature = "send")]
+type AutocompleteAction<'a, S> = Box<
+    dyn 'a
+        + Send
+        + Sync
+        + Fn(
+            S,
+            Interaction,
+            FocusedOption,
+        ) -> HandlerFuture<'a, Vec<ApplicationCommandOptionChoice<'static>>>,
+>;
+
+pub struct AutocompleteHandler<'a, S>(AutocompleteAction<'a, S>)
+where
+    S: DiscordState<'a>;
+
+impl<'a, S> AutocompleteHandler<'a, S>
+where
+    S: DiscordState<'a> + 'a,
+{
+    pub fn new<F, R>(handler: F) -> Self
+    where
+        F: Fn(S, Interaction, FocusedOption) -> R + MaybeSend + MaybeSync + 'a,
+        R: Future<Output = Result<Vec<ApplicationCommandOptionChoice<'static>>, DiscordError>>
+            + MaybeSend
+            + 'a,
+    {
+        Self(Box::new(move |state, interaction, focused| {
+            Box::pin(handler(state, interaction, focused))
+        }))
+    }
+}
+
 pub struct DiscordHandler<'a, S>
 where
     S: DiscordState<'a>,
 {
     commands: HashMap<Cow<'a, str>, CommandHandler<'a, S>>,
-    components: HashMap<Cow<'a, str>, ComponentHandler<'a, S>>,
+    subcommands: Vec<(SubcommandPath<'a>, SubcommandHandler<'a, S>)>,
+    groups: Vec<(GroupPath<'a>, Cow<'a, str>)>,
+    components: Vec<(CustomIdPattern, ComponentHandler<'a, S>)>,
+    component_fallback: Option<ComponentHandler<'a, S>>,
+    autocompletes: HashMap<Cow<'a, str>, HashMap<Cow<'a, str>, AutocompleteHandler<'a, S>>>,
+    modals: HashMap<Cow<'a, str>, ModalHandler<'a, S>>,
+    error_hook: Option<ErrorHook>,
+    replay_guard: Option<ReplayGuard>,
+}
+
+/// Turns a failed interaction into a response, or leaves it failed by returning `None`.
+pub type ErrorHook = fn(&DiscordError) -> Option<InteractionResponse>;
+
+impl<'a, S> Default for DiscordHandler<'a, S>
+where
+    S: DiscordState<'a>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a, S> DiscordHandler<'a, S>
@@ -67,7 +368,14 @@ where
     pub fn new() -> Self {
         Self {
             commands: HashMap::new(),
-            components: HashMap::new(),
+            subcommands: Vec::new(),
+            groups: Vec::new(),
+            components: Vec::new(),
+            component_fallback: None,
+            autocompletes: HashMap::new(),
+            modals: HashMap::new(),
+            error_hook: None,
+            replay_guard: None,
         }
     }
 
@@ -78,20 +386,142 @@ where
         self
     }
 
-    pub fn component(mut self, value: (&'a str, ComponentHandler<'a, S>)) -> Self {
-        let (key, value) = value;
-        self.components.insert(key.into(), value);
+    /// Registers a handler for a single subcommand. Subcommands without a handler of their own
+    /// fall through to the handler of the top level command.
+    pub fn subcommand<P>(mut self, path: P, value: SubcommandHandler<'a, S>) -> Self
+    where
+        P: Into<SubcommandPath<'a>>,
+    {
+        let path = path.into();
+        let SubcommandHandler { option, handler } = value;
+
+        let option = option
+            .name(path.subcommand().to_string())
+            .application_command_option_type(ApplicationCommandOptionType::SubCommand);
+
+        self.subcommands.retain(|(existing, _)| existing != &path);
+        self.subcommands
+            .push((path, SubcommandHandler { option, handler }));
+        self
+    }
+
+    /// Describes a command or subcommand group that has no handler of its own, only
+    /// subcommands.
+    pub fn group<P, D>(mut self, path: P, description: D) -> Self
+    where
+        P: Into<GroupPath<'a>>,
+        D: Into<Cow<'a, str>>,
+    {
+        let path = path.into();
+
+        self.groups.retain(|(existing, _)| existing != &path);
+        self.groups.push((path, description.into()));
+        self
+    }
+
+    /// Registers a handler for components whose `custom_id` matches `pattern`.
+    ///
+    /// Patterns are tried in registration order, e.g. `vote:{poll_id}:{choice}`.
+    pub fn component(mut self, value: (CustomIdPattern, ComponentHandler<'a, S>)) -> Self {
+        self.components.push(value);
+        self
+    }
+
+    pub fn component_prefix(mut self, value: (&'a str, ComponentHandler<'a, S>)) -> Self {
+        let (prefix, value) = value;
+        self.components
+            .push((CustomIdPattern::prefix(prefix), value));
+        self
+    }
+
+    pub fn component_fallback(mut self, value: ComponentHandler<'a, S>) -> Self {
+        self.component_fallback = Some(value);
         self
     }
 
-    pub fn command_list(&self) -> Vec<ApplicationCommand> {
-        let res = self
+    pub fn autocomplete(mut self, value: (&'a str, &'a str, AutocompleteHandler<'a, S>)) -> Self {
+        let (command, option, value) = value;
+        self.autocompletes
+            .entry(command.into())
+            .or_default()
+            .insert(option.into(), value);
+        self
+    }
+
+    pub fn modal(mut self, custom_id: &'a str, value: ModalHandler<'a, S>) -> Self {
+        self.modals.insert(custom_id.into(), value);
+        self
+    }
+
+    /// Sets a hook that can answer errors from routing and handlers instead of failing the
+    /// interaction, see `error::ephemeral_user_errors`.
+    pub fn on_error(mut self, hook: ErrorHook) -> Self {
+        self.error_hook = Some(hook);
+        self
+    }
+
+    /// Rejects requests with stale timestamps, and repeated interactions if the guard remembers
+    /// them.
+    pub fn replay_guard(mut self, guard: ReplayGuard) -> Self {
+        self.replay_guard = Some(guard);
+        self
+    }
+
+    /// Fails if a command or group made up of subcommands was not described with `group`.
+    pub fn command_list(&self) -> Result<Vec<ApplicationCommand<'a>>, MissingDescription> {
+        let mut res = self
             .commands
             .values()
             .map(|CommandHandler { command, .. }| command.clone())
             .collect::<Vec<_>>();
 
-        res
+        for (path, SubcommandHandler { option, .. }) in &self.subcommands {
+            let index = match res.iter().position(|c| c.name == path.command()) {
+                Some(index) => index,
+                None => {
+                    let description = self.group_description(&[path.command()])?;
+                    res.push(
+                        ApplicationCommand::new(path.command().to_string())
+                            .description(description),
+                    );
+                    res.len() - 1
+                }
+            };
+
+            let mut options = &mut res[index].options;
+
+            if let Some(group) = path.group() {
+                let index = match options.iter().position(|o| o.name == group) {
+                    Some(index) => index,
+                    None => {
+                        let description = self.group_description(&[path.command(), group])?;
+                        options.push(
+                            ApplicationCommandOption::new(group.to_string())
+                                .application_command_option_type(
+                                    ApplicationCommandOptionType::SubCommandGroup,
+                                )
+                                .description(description),
+                        );
+                        options.len() - 1
+                    }
+                };
+
+                options = &mut options[index].options;
+            }
+
+            options.retain(|o| o.name != option.name);
+            options.push(option.clone());
+        }
+
+        Ok(res)
+    }
+
+    fn group_description(&self, path: &[&str]) -> Result<Cow<'a, str>, MissingDescription> {
+        self.groups
+            .iter()
+            .find(|(registered, _)| registered == path)
+            .map(|(_, description)| description.clone())
+            .ok_or_else(|| MissingDescription(path.join(" ")))
     }
 
     pub async fn handle_request(
@@ -99,63 +529,100 @@ where
         state: S,
         body: String,
         headers: HeaderMap,
-    ) -> Result<InteractionResponse, StatusCode>
+    ) -> Result<InteractionResponse, DiscordError>
     where
         S: DiscordState<'a>,
     {
-        let verify = discord_verify(&state, &body, headers);
+        if let Some(guard) = &self.replay_guard {
+            guard
+                .verify_headers(&headers)
+                .map_err(DiscordError::Replay)?;
+        }
+
+        let verify = discord_verify(&state, body.as_bytes(), &headers);
 
         match verify {
-            DiscordVerify::Invalid => Err(StatusCode::UNAUTHORIZED),
-            DiscordVerify::Valid => {
-                let interaction = match serde_json::from_str::<Interaction>(&body) {
-                    Ok(i) => i,
-                    Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR),
-                };
+            DiscordVerify::Invalid(reason) => Err(DiscordError::InvalidSignature(reason)),
+            DiscordVerify::Valid(_) => {
+                let res = self.dispatch(state, &body).await;
 
-                let res = match interaction.interaction_type {
-                    InteractionType::Ping => InteractionResponse {
-                        response_type: InteractionCallbackType::Pong,
-                        data: None,
-                    },
-                    InteractionType::ApplicationCommand => {
-                        self.application_command(state, interaction).await?
-                    }
-                    InteractionType::MessageComponent => {
-                        self.message_component(state, interaction).await?
-                    }
-                    _ => {
-                        unimplemented!(
-                            "{:?} is not yet a supported message type",
-                            interaction.interaction_type
-                        );
+                match (res, self.error_hook) {
+                    (Err(e), Some(hook)) => {
+                        debug!("handle_request: {e}");
+                        hook(&e).ok_or(e)
                     }
-                };
-
-                Ok(res)
+                    (res, _) => res,
+                }
             }
         }
     }
 
+    async fn dispatch(&self, state: S, body: &str) -> Result<InteractionResponse, DiscordError> {
+        let deserializer = &mut serde_json::Deserializer::from_str(body);
+        let interaction: Interaction = serde_path_to_error::deserialize(deserializer)?;
+
+        if let Some(guard) = &self.replay_guard {
+            guard
+                .verify_interaction(interaction.id)
+                .map_err(DiscordError::Replay)?;
+        }
+
+        let res = match interaction.interaction_type {
+            InteractionType::Ping => InteractionResponse {
+                response_type: InteractionCallbackType::Pong,
+                data: None,
+            },
+            InteractionType::ApplicationCommand => {
+                self.application_command(state, interaction).await?
+            }
+            InteractionType::MessageComponent => self.message_component(state, interaction).await?,
+            InteractionType::ApplicationCommandAutocomplete => {
+                self.application_command_autocomplete(state, interaction)
+                    .await?
+            }
+            InteractionType::ModalSubmit => self.modal_submit(state, interaction).await?,
+            InteractionType::Unknown(value) => {
+                return Err(DiscordError::UnknownInteractionType(value))
+            }
+        };
+
+        Ok(res)
+    }
+
     pub async fn application_command(
         &self,
         state: S,
         interaction: Interaction,
-    ) -> Result<InteractionResponse, StatusCode> {
+    ) -> Result<InteractionResponse, DiscordError> {
         let data = interaction
             .data
             .as_ref()
-            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+            .ok_or(DiscordError::MissingData("data"))?;
 
         let name = data
             .name
             .as_ref()
-            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+            .ok_or(DiscordError::MissingData("data.name"))?;
+
+        let options = data.command_options();
+        let path = std::iter::once(name.as_str())
+            .chain(options.subcommand_path())
+            .collect::<Vec<_>>();
+
+        let subcommand = self
+            .subcommands
+            .iter()
+            .find(|(registered, _)| registered == path.as_slice());
+
+        if let Some((_, SubcommandHandler { handler, .. })) = subcommand {
+            let options = options.iter().cloned().collect();
+            return handler(state, interaction, options).await;
+        }
 
         let CommandHandler { handler, .. } = self
             .commands
             .get(name.as_str())
-            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+            .ok_or_else(|| DiscordError::UnknownCommand(path.join(" ")))?;
 
         handler(state, interaction).await
     }
@@ -164,18 +631,77 @@ where
         &self,
         state: S,
         interaction: Interaction,
-    ) -> Result<InteractionResponse, StatusCode> {
-        let name = &interaction
-            .message
+    ) -> Result<InteractionResponse, DiscordError> {
+        let custom_id = interaction
+            .data
             .as_ref()
-            .and_then(|message| message.interaction.as_ref())
-            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?
-            .name;
+            .and_then(|data| data.custom_id.as_ref())
+            .ok_or(DiscordError::MissingData("data.custom_id"))?;
 
-        let ComponentHandler(handler) = self
+        let (ComponentHandler(handler), params) = self
             .components
+            .iter()
+            .find_map(|(pattern, handler)| Some((handler, pattern.matches(custom_id)?)))
+            .or_else(|| {
+                self.component_fallback
+                    .as_ref()
+                    .map(|handler| (handler, CustomIdParams::default()))
+            })
+            .ok_or_else(|| DiscordError::UnknownComponent(custom_id.clone()))?;
+
+        handler(state, interaction, params).await
+    }
+
+    pub async fn application_command_autocomplete(
+        &self,
+        state: S,
+        interaction: Interaction,
+    ) -> Result<InteractionResponse, DiscordError> {
+        let data = interaction
+            .data
+            .as_ref()
+            .ok_or(DiscordError::MissingData("data"))?;
+
+        let name = data
+            .name
+            .as_ref()
+            .ok_or(DiscordError::MissingData("data.name"))?;
+
+        let focused = data
+            .focused_option()
+            .ok_or(DiscordError::MissingData("data.options.focused"))?;
+
+        let AutocompleteHandler(handler) = self
+            .autocompletes
             .get(name.as_str())
-            .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+            .and_then(|options| options.get(focused.name.as_str()))
+            .ok_or_else(|| DiscordError::UnknownAutocomplete {
+                command: name.clone(),
+                option: focused.name.clone(),
+            })?;
+
+        let choices = handler(state, interaction, focused).await?;
+
+        Ok(InteractionResponse::new()
+            .response_type(InteractionCallbackType::ApplicationCommandAutocompleteResult)
+            .data(InteractionCallbackData::new().choices(choices)))
+    }
+
+    pub async fn modal_submit(
+        &self,
+        state: S,
+        interaction: Interaction,
+    ) -> Result<InteractionResponse, DiscordError> {
+        let custom_id = interaction
+            .data
+            .as_ref()
+            .and_then(|data| data.custom_id.as_ref())
+            .ok_or(DiscordError::MissingData("data.custom_id"))?;
+
+        let ModalHandler(handler) = self
+            .modals
+            .get(custom_id.as_str())
+            .ok_or_else(|| DiscordError::UnknownComponent(custom_id.clone()))?;
 
         handler(state, interaction).await
     }
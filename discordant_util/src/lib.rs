@@ -1,20 +1,67 @@
-use std::{borrow::Cow, collections::HashMap, num::ParseIntError};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashMap,
+};
 
-use ed25519_dalek::{PublicKey, Signature, Verifier};
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
 use http::HeaderMap;
 use tracing::debug;
 
+pub mod custom_id;
+pub mod error;
+#[cfg(feature = "testing")]
+pub mod fixture;
 pub mod handler;
+pub mod replay;
+#[cfg(feature = "tower")]
+pub mod service;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod verifier;
+
+pub use verifier::Verifier;
 
 pub trait DiscordState<'a> {
     fn public_key(&self) -> Cow<'a, str>;
     fn application_id(&self) -> Cow<'a, str>;
+
+    /// Every key a request may be signed with, such as the old and new key while an application
+    /// is being recreated. Defaults to `public_key`.
+    fn public_keys(&self) -> Vec<Cow<'a, str>> {
+        vec![self.public_key()]
+    }
+
+    /// Verifiers built once from the public keys. When not empty, these are used instead of
+    /// decoding `public_keys` on every request.
+    fn verifiers(&self) -> &[Verifier] {
+        &[]
+    }
 }
 
+/// A `DiscordState` for a single key, built with `State::new`.
 #[derive(Debug, Clone)]
 pub struct State<'a> {
-    pub public_key: Cow<'a, str>,
-    pub application_id: Cow<'a, str>,
+    public_key: Cow<'a, str>,
+    application_id: Cow<'a, str>,
+    verifier: Verifier,
+}
+
+impl<'a> State<'a> {
+    /// Decodes `public_key` once, so a bad key fails here at startup rather than on every request.
+    pub fn new<K, A>(public_key: K, application_id: A) -> Result<Self, VerifyError>
+    where
+        K: Into<Cow<'a, str>>,
+        A: Into<Cow<'a, str>>,
+    {
+        let public_key = public_key.into();
+        let verifier = Verifier::new(&public_key)?;
+
+        Ok(Self {
+            public_key,
+            application_id: application_id.into(),
+            verifier,
+        })
+    }
 }
 
 impl<'a> DiscordState<'a> for State<'a> {
@@ -25,37 +72,144 @@ impl<'a> DiscordState<'a> for State<'a> {
     fn application_id(&self) -> Cow<'a, str> {
         self.application_id.clone()
     }
+
+    fn verifiers(&self) -> &[Verifier] {
+        std::slice::from_ref(&self.verifier)
+    }
 }
 
 #[derive(Debug)]
 pub enum DiscordVerify {
-    Valid,
-    Invalid,
+    /// Holds the index of the key that matched, in `DiscordState::verifiers` or, when there are
+    /// none, `DiscordState::public_keys`.
+    Valid(usize),
+    Invalid(InvalidReason),
+}
+
+/// Why a request failed verification.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum InvalidReason {
+    MissingHeader(&'static str),
+    MalformedSignature,
+    /// The configured public key could not be decoded, which points at misconfiguration rather
+    /// than a bad request.
+    BadKey,
+    SignatureMismatch,
+    MalformedTimestamp,
+    StaleTimestamp,
+    DuplicateInteraction,
 }
 
-pub fn discord_verify<'a, S>(state: &S, body: &str, headers: HeaderMap) -> DiscordVerify
+impl std::fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidReason::MissingHeader(name) => write!(f, "missing `{name}` header"),
+            InvalidReason::MalformedSignature => write!(f, "malformed signature"),
+            InvalidReason::BadKey => write!(f, "invalid public key"),
+            InvalidReason::SignatureMismatch => write!(f, "signature does not match"),
+            InvalidReason::MalformedTimestamp => write!(f, "malformed timestamp"),
+            InvalidReason::StaleTimestamp => write!(f, "stale timestamp"),
+            InvalidReason::DuplicateInteraction => write!(f, "duplicate interaction"),
+        }
+    }
+}
+
+impl From<VerifyError> for InvalidReason {
+    fn from(value: VerifyError) -> Self {
+        match value {
+            VerifyError::PublicKeyDecode | VerifyError::PublicKeyFromBytes => InvalidReason::BadKey,
+            VerifyError::SignatureDecode | VerifyError::SignatureFromBytes => {
+                InvalidReason::MalformedSignature
+            }
+            VerifyError::TimestampDecode => InvalidReason::MalformedTimestamp,
+            VerifyError::StaleTimestamp => InvalidReason::StaleTimestamp,
+            VerifyError::DuplicateInteraction => InvalidReason::DuplicateInteraction,
+        }
+    }
+}
+
+pub fn discord_verify<'a, S>(state: &S, body: &[u8], headers: &HeaderMap) -> DiscordVerify
 where
     S: DiscordState<'a>,
 {
-    let headers = headers
-        .iter()
-        .filter_map(|(name, value)| {
-            let v = value.to_str().ok()?;
-            match name.as_str() {
-                "x-signature-ed25519" | "x-signature-timestamp" => Some((name.as_str(), v)),
-                _ => None,
-            }
-        })
-        .collect::<HashMap<_, _>>();
+    let verify = verify_request(state, body, headers);
 
-    let public_key = state.public_key();
-    let valid = verify_signature(&headers, body, &public_key);
+    debug!("discord_verify: {verify:?}");
 
-    debug!("discord_verify: {valid:?}");
+    match verify {
+        Ok(key) => DiscordVerify::Valid(key),
+        Err(reason) => DiscordVerify::Invalid(reason),
+    }
+}
 
-    match valid {
-        Err(_) | Ok(false) => DiscordVerify::Invalid,
-        _ => DiscordVerify::Valid,
+fn verify_request<'a, S>(
+    state: &S,
+    body: &[u8],
+    headers: &HeaderMap,
+) -> Result<usize, InvalidReason>
+where
+    S: DiscordState<'a>,
+{
+    const TIMESTAMP: &str = "x-signature-timestamp";
+    const ED25519: &str = "x-signature-ed25519";
+
+    let timestamp = headers
+        .get(TIMESTAMP)
+        .ok_or(InvalidReason::MissingHeader(TIMESTAMP))?;
+    let signature = headers
+        .get(ED25519)
+        .ok_or(InvalidReason::MissingHeader(ED25519))?;
+
+    let signature = verifier::decode_signature(signature.as_bytes())?;
+
+    let verifiers = state.verifiers();
+
+    if verifiers.is_empty() {
+        let keys = state.public_keys();
+        let verifiers = keys.iter().map(|key| Verifier::new(key));
+
+        verify_keys(verifiers, timestamp.as_bytes(), &signature, body)
+    } else {
+        verify_keys(
+            verifiers.iter().map(Ok),
+            timestamp.as_bytes(),
+            &signature,
+            body,
+        )
+    }
+}
+
+/// Returns the index of the first key the signature matches. A key that fails to decode is only
+/// reported once no other key matched.
+fn verify_keys<I, V>(
+    verifiers: I,
+    timestamp: &[u8],
+    signature: &Signature,
+    body: &[u8],
+) -> Result<usize, InvalidReason>
+where
+    I: Iterator<Item = Result<V, VerifyError>>,
+    V: Borrow<Verifier>,
+{
+    let mut bad_key = false;
+
+    for (index, verifier) in verifiers.enumerate() {
+        let Ok(verifier) = verifier else {
+            bad_key = true;
+            continue;
+        };
+
+        if verifier
+            .borrow()
+            .verify_decoded(timestamp, signature, body)?
+        {
+            return Ok(index);
+        }
+    }
+
+    match bad_key {
+        true => Err(InvalidReason::BadKey),
+        false => Err(InvalidReason::SignatureMismatch),
     }
 }
 
@@ -65,8 +219,34 @@ pub enum VerifyError {
     PublicKeyFromBytes,
     SignatureDecode,
     SignatureFromBytes,
+    TimestampDecode,
+    /// The signed timestamp is further from the current time than the allowed skew.
+    StaleTimestamp,
+    DuplicateInteraction,
 }
 
+impl std::fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyError::PublicKeyDecode => write!(f, "public key is not valid hex"),
+            VerifyError::PublicKeyFromBytes => write!(f, "public key is not a valid ed25519 key"),
+            VerifyError::SignatureDecode => write!(f, "signature is not valid hex"),
+            VerifyError::SignatureFromBytes => {
+                write!(f, "signature is not a valid ed25519 signature")
+            }
+            VerifyError::TimestampDecode => {
+                write!(f, "signature timestamp is missing or malformed")
+            }
+            VerifyError::StaleTimestamp => {
+                write!(f, "signature timestamp is outside the allowed window")
+            }
+            VerifyError::DuplicateInteraction => write!(f, "interaction was already received"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
 pub fn verify_signature(
     headers: &HashMap<&str, &str>,
     body: &str,
@@ -86,15 +266,16 @@ pub fn verify_signature(
 
     let signature = {
         let signature = headers.get(ED25519).unwrap();
-        let signature_vec = decode_hex(signature).map_err(|_| VerifyError::SignatureDecode)?;
+        let signature_vec = decode_hex(signature).ok_or(VerifyError::SignatureDecode)?;
 
-        Signature::from_bytes(&signature_vec).map_err(|_| VerifyError::SignatureFromBytes)?
+        Signature::from_slice(&signature_vec).map_err(|_| VerifyError::SignatureFromBytes)?
     };
 
     let public_key = {
-        let public_key_vec = decode_hex(discord_key).map_err(|_| VerifyError::PublicKeyDecode)?;
+        let public_key_vec = decode_hex(discord_key).ok_or(VerifyError::PublicKeyDecode)?;
 
-        PublicKey::from_bytes(&public_key_vec).map_err(|_| VerifyError::PublicKeyFromBytes)?
+        VerifyingKey::try_from(public_key_vec.as_slice())
+            .map_err(|_| VerifyError::PublicKeyFromBytes)?
     };
 
     let verify = public_key.verify(timestamp_body.as_bytes(), &signature);
@@ -105,9 +286,9 @@ pub fn verify_signature(
     }
 }
 
-fn decode_hex(s: &str) -> Result<Vec<u8>, ParseIntError> {
-    (0..s.len())
-        .step_by(2)
-        .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-        .collect()
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![0; s.len() / 2];
+    verifier::decode_hex_into(s.as_bytes(), &mut bytes)?;
+
+    Some(bytes)
 }
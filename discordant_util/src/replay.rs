@@ -0,0 +1,145 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use discordant_types::{Id, InteractionMarker};
+use http::HeaderMap;
+
+use crate::VerifyError;
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> SystemTime,
+{
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+/// Rejects requests whose signed timestamp is too far from the current time, and optionally
+/// interactions that have already been seen.
+pub struct ReplayGuard {
+    max_skew: Duration,
+    clock: Box<dyn Clock + Send + Sync>,
+    seen: Option<Mutex<SeenInteractions>>,
+}
+
+impl ReplayGuard {
+    pub fn new(max_skew: Duration) -> Self {
+        Self {
+            max_skew,
+            clock: Box::new(SystemClock),
+            seen: None,
+        }
+    }
+
+    pub fn clock<C>(mut self, value: C) -> Self
+    where
+        C: Clock + Send + Sync + 'static,
+    {
+        self.clock = Box::new(value);
+        self
+    }
+
+    /// Remembers the ids of the last `capacity` interactions and rejects any that repeat.
+    pub fn remember(mut self, capacity: usize) -> Self {
+        self.seen = Some(Mutex::new(SeenInteractions::new(capacity)));
+        self
+    }
+
+    pub fn verify_headers(&self, headers: &HeaderMap) -> Result<(), VerifyError> {
+        let timestamp = headers
+            .get("x-signature-timestamp")
+            .and_then(|value| value.to_str().ok())
+            .ok_or(VerifyError::TimestampDecode)?;
+
+        self.verify_timestamp(timestamp)
+    }
+
+    /// Checks an `x-signature-timestamp` value, given in seconds since the Unix epoch.
+    pub fn verify_timestamp(&self, timestamp: &str) -> Result<(), VerifyError> {
+        let seconds = timestamp
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| VerifyError::TimestampDecode)?;
+
+        let signed_at = UNIX_EPOCH + Duration::from_secs(seconds);
+        let now = self.clock.now();
+
+        let skew = now
+            .duration_since(signed_at)
+            .or_else(|_| signed_at.duration_since(now))
+            .unwrap_or_default();
+
+        if skew > self.max_skew {
+            return Err(VerifyError::StaleTimestamp);
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_interaction(&self, id: Id<InteractionMarker>) -> Result<(), VerifyError> {
+        let Some(seen) = &self.seen else {
+            return Ok(());
+        };
+
+        let mut seen = seen.lock().unwrap_or_else(|e| e.into_inner());
+
+        if seen.insert(id) {
+            Ok(())
+        } else {
+            Err(VerifyError::DuplicateInteraction)
+        }
+    }
+}
+
+struct SeenInteractions {
+    capacity: usize,
+    order: VecDeque<Id<InteractionMarker>>,
+    ids: HashSet<Id<InteractionMarker>>,
+}
+
+impl SeenInteractions {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            ids: HashSet::with_capacity(capacity),
+        }
+    }
+
+    /// Returns `false` if `id` was already present.
+    fn insert(&mut self, id: Id<InteractionMarker>) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+
+        if !self.ids.insert(id) {
+            return false;
+        }
+
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(id);
+        true
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    convert::Infallible,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Buf;
+use futures_util::future::BoxFuture;
+use http::{header, Method, Request, Response, StatusCode};
+use http_body::Body;
+use tower_service::Service;
+
+use crate::{handler::DiscordHandler, DiscordState};
+
+const DEFAULT_BODY_LIMIT: usize = 1024 * 1024;
+
+/// Serves a `DiscordHandler` as a `tower::Service`, so it can be mounted as an axum or hyper route.
+pub struct DiscordService<S>
+where
+    S: DiscordState<'static>,
+{
+    handler: Arc<DiscordHandler<'static, S>>,
+    state: S,
+    body_limit: usize,
+}
+
+impl<S> DiscordService<S>
+where
+    S: DiscordState<'static>,
+{
+    pub fn new(handler: DiscordHandler<'static, S>, state: S) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            state,
+            body_limit: DEFAULT_BODY_LIMIT,
+        }
+    }
+
+    /// The largest request body accepted, in bytes. Defaults to 1 MiB.
+    pub fn body_limit(mut self, value: usize) -> Self {
+        self.body_limit = value;
+        self
+    }
+}
+
+impl<S> Clone for DiscordService<S>
+where
+    S: DiscordState<'static> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+            state: self.state.clone(),
+            body_limit: self.body_limit,
+        }
+    }
+}
+
+impl<S> DiscordHandler<'static, S>
+where
+    S: DiscordState<'static>,
+{
+    pub fn into_service(self, state: S) -> DiscordService<S> {
+        DiscordService::new(self, state)
+    }
+}
+
+impl<S, B> Service<Request<B>> for DiscordService<S>
+where
+    S: DiscordState<'static> + Clone + Send + Sync + 'static,
+    B: Body + Send + 'static,
+    B::Data: Send,
+{
+    type Response = Response<String>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let handler = self.handler.clone();
+        let state = self.state.clone();
+        let body_limit = self.body_limit;
+
+        Box::pin(async move {
+            if req.method() != Method::POST {
+                return Ok(status(StatusCode::METHOD_NOT_ALLOWED));
+            }
+
+            let (parts, body) = req.into_parts();
+
+            let body = match read_body(body, body_limit).await {
+                Ok(body) => body,
+                Err(code) => return Ok(status(code)),
+            };
+
+            let res = match handler.handle_request(state, body, parts.headers).await {
+                Ok(res) => res,
+                Err(e) => return Ok(status(e.status_code())),
+            };
+
+            let res = match serde_json::to_string(&res) {
+                Ok(json) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(json)
+                    .unwrap_or_else(|_| status(StatusCode::INTERNAL_SERVER_ERROR)),
+                Err(_) => status(StatusCode::INTERNAL_SERVER_ERROR),
+            };
+
+            Ok(res)
+        })
+    }
+}
+
+async fn read_body<B>(body: B, limit: usize) -> Result<String, StatusCode>
+where
+    B: Body,
+{
+    let mut body = Box::pin(body);
+    let mut buf = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let mut chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        if buf.len() + chunk.remaining() > limit {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        while chunk.has_remaining() {
+            let bytes = chunk.chunk();
+            buf.extend_from_slice(bytes);
+            let len = bytes.len();
+            chunk.advance(len);
+        }
+    }
+
+    String::from_utf8(buf).map_err(|_| StatusCode::BAD_REQUEST)
+}
+
+fn status(code: StatusCode) -> Response<String> {
+    let mut res = Response::new(String::new());
+    *res.status_mut() = code;
+    res
+}
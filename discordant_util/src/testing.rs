@@ -0,0 +1,109 @@
+use std::{
+    fmt::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use discordant_types::{ApplicationMarker, Id};
+use ed25519_dalek::{Signer, SigningKey, SECRET_KEY_LENGTH};
+use http::{header, HeaderMap, HeaderValue, Method, Request};
+
+use crate::State;
+
+/// An ed25519 keypair standing in for Discord, so signed requests can be built without a real
+/// application.
+#[derive(Debug, Clone)]
+pub struct TestKey {
+    signing_key: SigningKey,
+}
+
+impl TestKey {
+    pub fn generate() -> Self {
+        let mut seed = [0; SECRET_KEY_LENGTH];
+        getrandom::getrandom(&mut seed).expect("could not generate a test key");
+
+        Self::from_seed(seed)
+    }
+
+    /// A key that is the same on every run.
+    pub fn from_seed(seed: [u8; SECRET_KEY_LENGTH]) -> Self {
+        Self {
+            signing_key: SigningKey::from_bytes(&seed),
+        }
+    }
+
+    /// The hex encoded public key, as Discord shows it in the developer portal.
+    pub fn public_key(&self) -> String {
+        encode_hex(self.signing_key.verifying_key().as_bytes())
+    }
+
+    pub fn state<'a>(&self, application_id: Id<ApplicationMarker>) -> State<'a> {
+        State::new(self.public_key(), application_id.to_string())
+            .expect("a test key is always valid")
+    }
+
+    /// Signs `body` with the current time as the timestamp.
+    pub fn sign<T>(&self, body: T) -> SignedRequest
+    where
+        T: Into<String>,
+    {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        self.sign_at(timestamp, body)
+    }
+
+    pub fn sign_at<T>(&self, timestamp: u64, body: T) -> SignedRequest
+    where
+        T: Into<String>,
+    {
+        let body = body.into();
+        let timestamp = timestamp.to_string();
+        let signature = self
+            .signing_key
+            .sign(format!("{timestamp}{body}").as_bytes());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        headers.insert(
+            "x-signature-ed25519",
+            HeaderValue::from_str(&encode_hex(&signature.to_bytes()))
+                .expect("hex is a valid header value"),
+        );
+        headers.insert(
+            "x-signature-timestamp",
+            HeaderValue::from_str(&timestamp).expect("digits are a valid header value"),
+        );
+
+        SignedRequest { body, headers }
+    }
+}
+
+/// A request body with the `x-signature-*` headers Discord would send with it.
+#[derive(Debug, Clone)]
+pub struct SignedRequest {
+    pub body: String,
+    pub headers: HeaderMap,
+}
+
+impl SignedRequest {
+    pub fn into_request(self) -> Request<String> {
+        let mut req = Request::new(self.body);
+        *req.method_mut() = Method::POST;
+        *req.headers_mut() = self.headers;
+        req
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut s, b| {
+            let _ = write!(s, "{b:02x}");
+            s
+        })
+}
@@ -0,0 +1,96 @@
+use ed25519_dalek::{Signature, VerifyingKey, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+use http::HeaderMap;
+
+use crate::VerifyError;
+
+/// Verifies request signatures against a public key decoded once, up front.
+///
+/// Verification reads the timestamp and signature straight from the headers and streams the
+/// timestamp and body into the signature check, without allocating.
+#[derive(Debug, Clone)]
+pub struct Verifier {
+    key: VerifyingKey,
+}
+
+impl Verifier {
+    pub fn new(public_key: &str) -> Result<Self, VerifyError> {
+        let mut bytes = [0; PUBLIC_KEY_LENGTH];
+        decode_hex_into(public_key.as_bytes(), &mut bytes).ok_or(VerifyError::PublicKeyDecode)?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(public_key: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, VerifyError> {
+        let key =
+            VerifyingKey::from_bytes(public_key).map_err(|_| VerifyError::PublicKeyFromBytes)?;
+
+        Ok(Self { key })
+    }
+
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<bool, VerifyError> {
+        let (Some(timestamp), Some(signature)) = (
+            headers.get("x-signature-timestamp"),
+            headers.get("x-signature-ed25519"),
+        ) else {
+            return Ok(false);
+        };
+
+        self.verify_parts(timestamp.as_bytes(), signature.as_bytes(), body)
+    }
+
+    /// Verifies `signature`, hex encoded, over the timestamp followed by the body.
+    pub fn verify_parts(
+        &self,
+        timestamp: &[u8],
+        signature: &[u8],
+        body: &[u8],
+    ) -> Result<bool, VerifyError> {
+        let signature = decode_signature(signature)?;
+
+        self.verify_decoded(timestamp, &signature, body)
+    }
+
+    pub(crate) fn verify_decoded(
+        &self,
+        timestamp: &[u8],
+        signature: &Signature,
+        body: &[u8],
+    ) -> Result<bool, VerifyError> {
+        let Ok(mut verifier) = self.key.verify_stream(signature) else {
+            return Err(VerifyError::SignatureFromBytes);
+        };
+
+        verifier.update(timestamp);
+        verifier.update(body);
+
+        Ok(verifier.finalize_and_verify().is_ok())
+    }
+}
+
+pub(crate) fn decode_signature(signature: &[u8]) -> Result<Signature, VerifyError> {
+    let mut bytes = [0; SIGNATURE_LENGTH];
+    decode_hex_into(signature, &mut bytes).ok_or(VerifyError::SignatureDecode)?;
+
+    Ok(Signature::from_bytes(&bytes))
+}
+
+pub(crate) fn decode_hex_into(s: &[u8], out: &mut [u8]) -> Option<()> {
+    if s.len() != out.len() * 2 {
+        return None;
+    }
+
+    for (byte, pair) in out.iter_mut().zip(s.chunks_exact(2)) {
+        *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+    }
+
+    Some(())
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
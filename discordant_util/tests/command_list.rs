@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+
+use discordant_types::{ApplicationCommand, ApplicationCommandOption, InteractionResponse};
+use discordant_util::{
+    handler::{CommandHandler, DiscordHandler, MissingDescription, SubcommandHandler},
+    DiscordState,
+};
+use serde_json::json;
+
+#[derive(Clone)]
+struct NoKeys;
+
+impl<'a> DiscordState<'a> for NoKeys {
+    fn public_key(&self) -> Cow<'a, str> {
+        Cow::Borrowed("")
+    }
+
+    fn application_id(&self) -> Cow<'a, str> {
+        Cow::Borrowed("1")
+    }
+}
+
+fn subcommand(description: &'static str) -> SubcommandHandler<'static, NoKeys> {
+    SubcommandHandler::new(
+        ApplicationCommandOption::new("").description(description),
+        |_, _, _| async { Ok(InteractionResponse::new()) },
+    )
+}
+
+fn handler() -> DiscordHandler<'static, NoKeys> {
+    DiscordHandler::new()
+        .command(CommandHandler::new(
+            ApplicationCommand::new("ping").description("Check the bot is up"),
+            |_, _| async { Ok(InteractionResponse::new()) },
+        ))
+        .subcommand(("config", "reset"), subcommand("Reset every setting"))
+        .subcommand(("config", "set", "prefix"), subcommand("Set the prefix"))
+        .subcommand(("config", "set", "locale"), subcommand("Set the locale"))
+}
+
+#[test]
+fn synthesized_commands_use_group_descriptions() {
+    let handler = handler()
+        .group("config", "Change settings")
+        .group(("config", "set"), "Set a value");
+
+    let mut commands = handler.command_list().unwrap();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let config = serde_json::to_value(&commands[0]).unwrap();
+
+    assert_eq!(config["name"], "config");
+    assert_eq!(config["description"], "Change settings");
+    assert_eq!(
+        config["options"],
+        json!([
+            { "type": 1, "name": "reset", "description": "Reset every setting" },
+            {
+                "type": 2,
+                "name": "set",
+                "description": "Set a value",
+                "options": [
+                    { "type": 1, "name": "prefix", "description": "Set the prefix" },
+                    { "type": 1, "name": "locale", "description": "Set the locale" },
+                ],
+            },
+        ])
+    );
+
+    assert_eq!(commands[1].name, "ping");
+    assert_eq!(commands[1].description, "Check the bot is up");
+}
+
+#[test]
+fn subcommands_join_registered_commands() {
+    let handler = DiscordHandler::<NoKeys>::new()
+        .command(CommandHandler::new(
+            ApplicationCommand::new("config").description("Show settings"),
+            |_, _| async { Ok(InteractionResponse::new()) },
+        ))
+        .subcommand(("config", "reset"), subcommand("Reset every setting"));
+
+    let commands = handler.command_list().unwrap();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].description, "Show settings");
+    assert_eq!(commands[0].options[0].name, "reset");
+}
+
+#[test]
+fn undescribed_groups_are_reported() {
+    assert_eq!(
+        handler().command_list().unwrap_err(),
+        MissingDescription("config".to_string())
+    );
+    assert_eq!(
+        handler()
+            .group("config", "Change settings")
+            .command_list()
+            .unwrap_err(),
+        MissingDescription("config set".to_string())
+    );
+}
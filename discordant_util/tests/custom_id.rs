@@ -0,0 +1,107 @@
+use discordant_util::custom_id::{CustomIdParams, CustomIdPattern, CustomIdPatternError};
+
+fn pattern(pattern: &str) -> CustomIdPattern {
+    CustomIdPattern::new(pattern).unwrap()
+}
+
+fn params(params: &CustomIdParams, names: &[&str]) -> Vec<Option<String>> {
+    names
+        .iter()
+        .map(|name| params.get(name).map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn literal() {
+    let pattern = pattern("confirm");
+
+    assert!(pattern.matches("confirm").unwrap().is_empty());
+    assert!(pattern.matches("confirm:1").is_none());
+    assert!(pattern.matches("confir").is_none());
+    assert!(pattern.matches("").is_none());
+}
+
+#[test]
+fn params_between_literals() {
+    let pattern = pattern("vote:{poll}:{choice}");
+    let matched = pattern.matches("vote:12:yes").unwrap();
+
+    assert_eq!(
+        params(&matched, &["poll", "choice", "vote"]),
+        [Some("12".to_string()), Some("yes".to_string()), None]
+    );
+
+    // Everything up to the next literal is captured, the rest goes to the trailing param
+    let matched = pattern.matches("vote:12:yes:no").unwrap();
+    assert_eq!(matched.get("choice"), Some("yes:no"));
+
+    assert!(pattern.matches("vote:12").is_none());
+    assert!(pattern.matches("poll:12:yes").is_none());
+}
+
+#[test]
+fn trailing_literal() {
+    let pattern = pattern("page:{page}:next");
+
+    assert_eq!(
+        pattern.matches("page:3:next").unwrap().get("page"),
+        Some("3")
+    );
+    assert!(pattern.matches("page:3:prev").is_none());
+    assert!(pattern.matches("page:3:next:more").is_none());
+}
+
+#[test]
+fn empty_captures_do_not_match() {
+    let pattern = pattern("vote:{poll}:{choice}");
+
+    assert!(pattern.matches("vote::yes").is_none());
+    assert!(pattern.matches("vote:12:").is_none());
+
+    let pattern = self::pattern("{id}");
+
+    assert_eq!(pattern.matches("abc").unwrap().get("id"), Some("abc"));
+    assert!(pattern.matches("").is_none());
+}
+
+#[test]
+fn prefix() {
+    let pattern = CustomIdPattern::prefix("menu:");
+
+    assert!(pattern.matches("menu:").unwrap().is_empty());
+    assert!(pattern.matches("menu:anything:else").unwrap().is_empty());
+    assert!(pattern.matches("men").is_none());
+    assert!(pattern.matches("other:menu:").is_none());
+
+    // Braces have no meaning in a prefix
+    let pattern = CustomIdPattern::prefix("{id}");
+
+    assert!(pattern.matches("{id}:1").is_some());
+    assert!(pattern.matches("1").is_none());
+}
+
+#[test]
+fn unclosed_brace_is_literal() {
+    let pattern = pattern("user:{id");
+
+    assert!(pattern.matches("user:{id").unwrap().is_empty());
+    assert!(pattern.matches("user:1").is_none());
+
+    let pattern = self::pattern("{id}:{rest");
+
+    assert_eq!(pattern.matches("1:{rest").unwrap().get("id"), Some("1"));
+    assert!(pattern.matches("1:2").is_none());
+}
+
+#[test]
+fn adjacent_params_are_rejected() {
+    assert_eq!(
+        CustomIdPattern::new("{a}{b}"),
+        Err(CustomIdPatternError::AdjacentParams(
+            "a".to_string(),
+            "b".to_string()
+        ))
+    );
+    assert!(CustomIdPattern::new("x:{a}{b}:y").is_err());
+    assert!(CustomIdPattern::new("{a}-{b}").is_ok());
+}
@@ -0,0 +1,129 @@
+use discordant_types::OptionError;
+use discordant_util::{
+    error::{ephemeral_user_errors, DiscordError},
+    InvalidReason, VerifyError,
+};
+use http::StatusCode;
+use serde_json::json;
+
+fn errors() -> Vec<(DiscordError, StatusCode)> {
+    vec![
+        (
+            DiscordError::InvalidSignature(InvalidReason::SignatureMismatch),
+            StatusCode::UNAUTHORIZED,
+        ),
+        (
+            DiscordError::Replay(VerifyError::StaleTimestamp),
+            StatusCode::UNAUTHORIZED,
+        ),
+        (
+            DiscordError::Decode {
+                path: "data.name".to_string(),
+                message: "invalid type".to_string(),
+            },
+            StatusCode::BAD_REQUEST,
+        ),
+        (DiscordError::MissingData("data"), StatusCode::BAD_REQUEST),
+        (
+            DiscordError::Options(OptionError::Missing("text".to_string())),
+            StatusCode::BAD_REQUEST,
+        ),
+        (DiscordError::user("no"), StatusCode::BAD_REQUEST),
+        (
+            DiscordError::UnknownCommand("echo".to_string()),
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            DiscordError::UnknownComponent("vote".to_string()),
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            DiscordError::UnknownAutocomplete {
+                command: "search".to_string(),
+                option: "query".to_string(),
+            },
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            DiscordError::UnknownInteractionType(42),
+            StatusCode::NOT_FOUND,
+        ),
+        (
+            DiscordError::handler("database is down"),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ),
+    ]
+}
+
+#[test]
+fn status_codes() {
+    for (error, status) in errors() {
+        assert_eq!(error.status_code(), status, "{error:?}");
+        assert_eq!(StatusCode::from(error), status);
+    }
+}
+
+#[test]
+fn only_user_and_option_errors_are_user_facing() {
+    for (error, _) in errors() {
+        let expected = matches!(error, DiscordError::User(_) | DiscordError::Options(_));
+
+        assert_eq!(error.is_user_facing(), expected, "{error:?}");
+    }
+}
+
+#[test]
+fn user_facing_errors_are_answered_ephemerally() {
+    let res = ephemeral_user_errors(&DiscordError::user("Pick a smaller number")).unwrap();
+    assert_eq!(
+        serde_json::to_value(res).unwrap(),
+        json!({
+            "type": 4,
+            "data": { "content": "Pick a smaller number", "flags": 64 },
+        })
+    );
+
+    let res = ephemeral_user_errors(&DiscordError::Options(OptionError::Missing(
+        "text".to_string(),
+    )))
+    .unwrap();
+    assert_eq!(
+        res.data.unwrap().content.as_deref(),
+        Some("option `text` is missing")
+    );
+
+    for (error, _) in errors() {
+        if !error.is_user_facing() {
+            assert!(ephemeral_user_errors(&error).is_none(), "{error:?}");
+        }
+    }
+}
+
+#[test]
+fn verify_errors_keep_their_reason() {
+    let reasons = [
+        (VerifyError::PublicKeyDecode, InvalidReason::BadKey),
+        (VerifyError::PublicKeyFromBytes, InvalidReason::BadKey),
+        (
+            VerifyError::SignatureDecode,
+            InvalidReason::MalformedSignature,
+        ),
+        (
+            VerifyError::SignatureFromBytes,
+            InvalidReason::MalformedSignature,
+        ),
+        (
+            VerifyError::TimestampDecode,
+            InvalidReason::MalformedTimestamp,
+        ),
+        (VerifyError::StaleTimestamp, InvalidReason::StaleTimestamp),
+        (
+            VerifyError::DuplicateInteraction,
+            InvalidReason::DuplicateInteraction,
+        ),
+    ];
+
+    for (error, reason) in reasons {
+        assert_eq!(InvalidReason::from(error), reason);
+    }
+}
@@ -0,0 +1,299 @@
+use discordant_types::{
+    ApplicationCommandInteractionDataOption, ApplicationCommandType, ChannelType, ComponentType,
+    Id, InteractionType,
+};
+use discordant_util::fixture::{
+    self, InteractionBuilder, MemberFixture, OptionFixture, UserFixture,
+};
+
+#[test]
+fn ping() {
+    let interaction = InteractionBuilder::ping().build();
+
+    assert_eq!(interaction.interaction_type, InteractionType::Ping);
+    assert!(interaction.data.is_none());
+}
+
+#[test]
+fn slash_command_with_every_option() {
+    let interaction = InteractionBuilder::slash_command("everything")
+        .option(OptionFixture::string("string", "text"))
+        .option(OptionFixture::integer("integer", -4))
+        .option(OptionFixture::boolean("boolean", true))
+        .option(OptionFixture::user(
+            "user",
+            UserFixture::new(Id::new(1), "one"),
+        ))
+        .option(OptionFixture::member(
+            "member",
+            MemberFixture::new(UserFixture::new(Id::new(2), "two")).nick("deux"),
+        ))
+        .option(OptionFixture::channel(
+            "channel",
+            Id::new(3),
+            "general",
+            ChannelType::GuildText,
+        ))
+        .option(OptionFixture::role("role", Id::new(4), "mods"))
+        .option(OptionFixture::mentionable_role(
+            "mentionable_role",
+            Id::new(5),
+            "admins",
+        ))
+        .option(OptionFixture::mentionable_user(
+            "mentionable_user",
+            UserFixture::new(Id::new(6), "six"),
+        ))
+        .option(OptionFixture::number("number", 1.5))
+        .option(OptionFixture::attachment(
+            "attachment",
+            Id::new(7),
+            "cat.png",
+        ))
+        .build();
+
+    assert_eq!(
+        interaction.interaction_type,
+        InteractionType::ApplicationCommand
+    );
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.name.as_deref(), Some("everything"));
+    assert_eq!(data.data_type, Some(ApplicationCommandType::ChatInput));
+    assert_eq!(data.options.len(), 11);
+
+    let resolved = data.resolved.unwrap();
+    let users = resolved.users.unwrap();
+    assert_eq!(users.len(), 3);
+    assert_eq!(users[&Id::new(6)].username, "six");
+    assert_eq!(
+        resolved.members.unwrap()[&Id::new(2)].nick.as_deref(),
+        Some("deux")
+    );
+    assert_eq!(
+        resolved.channels.unwrap()[&Id::new(3)].name.as_deref(),
+        Some("general")
+    );
+    assert_eq!(resolved.roles.unwrap().len(), 2);
+    assert_eq!(
+        resolved.attachments.unwrap()[&Id::new(7)].filename,
+        "cat.png"
+    );
+}
+
+#[test]
+fn subcommands() {
+    let interaction = InteractionBuilder::slash_command("config")
+        .option(OptionFixture::group(
+            "prefix",
+            [OptionFixture::subcommand(
+                "set",
+                [OptionFixture::string("value", "!")],
+            )],
+        ))
+        .build();
+
+    let data = interaction.data.unwrap();
+    let options = data.command_options();
+
+    assert_eq!(
+        options.subcommand_path().collect::<Vec<_>>(),
+        ["prefix", "set"]
+    );
+    assert!(data.resolved.is_none());
+}
+
+#[test]
+fn user_command() {
+    let target = MemberFixture::new(UserFixture::new(Id::new(1), "target")).role(Id::new(2));
+    let interaction = InteractionBuilder::user_command("inspect", target).build();
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.data_type, Some(ApplicationCommandType::User));
+    assert_eq!(data.target_id, Some(Id::new(1)));
+
+    let resolved = data.resolved.unwrap();
+    assert_eq!(resolved.users.unwrap()[&Id::new(1)].username, "target");
+    assert!(resolved.members.unwrap()[&Id::new(1)]
+        .roles
+        .contains(&Id::new(2)));
+}
+
+#[test]
+fn options_keep_the_resolved_target() {
+    let target = MemberFixture::new(UserFixture::new(Id::new(1), "target"));
+    let interaction = InteractionBuilder::user_command("inspect", target)
+        .option(OptionFixture::role("role", Id::new(2), "mods"))
+        .build();
+
+    let resolved = interaction.data.unwrap().resolved.unwrap();
+    assert!(resolved.users.unwrap().contains_key(&Id::new(1)));
+    assert!(resolved.members.unwrap().contains_key(&Id::new(1)));
+    assert!(resolved.roles.unwrap().contains_key(&Id::new(2)));
+
+    let interaction = InteractionBuilder::message_command(
+        "quote",
+        Id::new(3),
+        UserFixture::new(Id::new(4), "author"),
+        "hello",
+    )
+    .option(OptionFixture::user(
+        "user",
+        UserFixture::new(Id::new(5), "other"),
+    ))
+    .build();
+
+    let resolved = interaction.data.unwrap().resolved.unwrap();
+    assert!(resolved.messages.unwrap().contains_key(&Id::new(3)));
+    assert!(resolved.users.unwrap().contains_key(&Id::new(5)));
+}
+
+#[test]
+fn message_command() {
+    let interaction = InteractionBuilder::message_command(
+        "quote",
+        Id::new(1),
+        UserFixture::new(Id::new(2), "author"),
+        "hi",
+    )
+    .build();
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.data_type, Some(ApplicationCommandType::Message));
+    assert_eq!(data.target_id, Some(Id::new(1)));
+
+    let message = &data.resolved.unwrap().messages.unwrap()[&Id::new(1)];
+    assert_eq!(message.content, "hi");
+    assert_eq!(message.author.id, Id::new(2));
+}
+
+#[test]
+fn button() {
+    let interaction = InteractionBuilder::button("vote:1:yes")
+        .channel_id(Id::new(1))
+        .build();
+
+    assert_eq!(
+        interaction.interaction_type,
+        InteractionType::MessageComponent
+    );
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.custom_id.as_deref(), Some("vote:1:yes"));
+    assert_eq!(data.component_type, Some(ComponentType::Button));
+
+    let message = interaction.message.unwrap();
+    assert_eq!(message.channel_id, Id::new(1));
+    assert_eq!(message.author.id, fixture::APPLICATION_ID.cast());
+}
+
+#[test]
+fn select() {
+    let interaction = InteractionBuilder::select("colour", ["red", "blue"]).build();
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.component_type, Some(ComponentType::SelectMenu));
+    assert_eq!(data.values, ["red", "blue"]);
+    assert!(interaction.message.is_some());
+}
+
+#[test]
+fn modal_submit() {
+    let interaction = InteractionBuilder::modal_submit("feedback")
+        .text_input("title", "Great")
+        .text_input("body", "Thanks")
+        .build();
+
+    assert_eq!(interaction.interaction_type, InteractionType::ModalSubmit);
+
+    let data = interaction.data.unwrap();
+    assert_eq!(data.custom_id.as_deref(), Some("feedback"));
+    assert_eq!(data.component_value("title"), Some("Great"));
+    assert_eq!(data.component_value("body"), Some("Thanks"));
+    assert_eq!(data.component_value("missing"), None);
+}
+
+#[test]
+fn autocomplete() {
+    let interaction = InteractionBuilder::autocomplete("search")
+        .option(OptionFixture::string("query", "rus").focused())
+        .option(OptionFixture::integer("limit", 5))
+        .build();
+
+    assert_eq!(
+        interaction.interaction_type,
+        InteractionType::ApplicationCommandAutocomplete
+    );
+
+    let data = interaction.data.unwrap();
+    let focused = data.focused_option().unwrap();
+    assert_eq!(focused.name, "query");
+    assert_eq!(focused.value, "rus");
+    assert!(matches!(
+        &data.options[1],
+        ApplicationCommandInteractionDataOption::Integer { value: Some(5), .. }
+    ));
+}
+
+#[test]
+fn context() {
+    let member = MemberFixture::new(
+        UserFixture::new(Id::new(1), "member")
+            .global_name("Member")
+            .avatar("abc"),
+    )
+    .nick("nick")
+    .role(Id::new(2))
+    .permissions("8")
+    .communication_disabled_until("2030-01-01T00:00:00.000000+00:00");
+
+    let interaction = InteractionBuilder::slash_command("ping")
+        .id(Id::new(10))
+        .application_id(Id::new(11))
+        .command_id(Id::new(12))
+        .guild_id(Id::new(13))
+        .channel_id(Id::new(14))
+        .member(member)
+        .token("token")
+        .locale("fr")
+        .app_permissions("0")
+        .build();
+
+    assert_eq!(interaction.id, Id::new(10));
+    assert_eq!(interaction.application_id, Id::new(11));
+    assert_eq!(interaction.data.unwrap().id, Some(Id::new(12)));
+    assert_eq!(interaction.guild_id, Some(Id::new(13)));
+    assert_eq!(interaction.channel_id, Some(Id::new(14)));
+    assert_eq!(interaction.token, "token");
+    assert_eq!(interaction.locale.as_deref(), Some("fr"));
+    assert!(interaction.user.is_none());
+
+    let member = interaction.member.unwrap();
+    let user = member.user.unwrap();
+    assert_eq!(user.id, Id::new(1));
+    assert_eq!(user.global_name.as_deref(), Some("Member"));
+    assert_eq!(member.nick.as_deref(), Some("nick"));
+    assert!(member.roles.contains(&Id::new(2)));
+    assert!(member.communication_disabled_until.is_some());
+}
+
+#[test]
+fn dm() {
+    let interaction = InteractionBuilder::button("close")
+        .dm(UserFixture::new(Id::new(1), "friend").bot(true))
+        .build();
+
+    assert!(interaction.guild_id.is_none());
+    assert!(interaction.member.is_none());
+    assert_eq!(interaction.user.unwrap().id, Id::new(1));
+}
+
+#[test]
+fn json_matches_value() {
+    let builder = InteractionBuilder::slash_command("ping");
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&builder.to_json()).unwrap(),
+        builder.to_value()
+    );
+}
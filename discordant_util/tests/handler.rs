@@ -0,0 +1,302 @@
+use std::time::Duration;
+
+use discordant_types::{
+    ApplicationCommand, ApplicationCommandOption, ApplicationCommandOptionChoice,
+    ApplicationCommandOptionChoiceValue, Id, InteractionCallbackData, InteractionCallbackType,
+    InteractionResponse, RawOptionValue,
+};
+use discordant_util::{
+    custom_id::CustomIdPattern,
+    error::{ephemeral_user_errors, DiscordError},
+    fixture::{self, InteractionBuilder, OptionFixture},
+    handler::{
+        AutocompleteHandler, CommandHandler, ComponentHandler, DiscordHandler, ModalHandler,
+        SubcommandHandler,
+    },
+    replay::ReplayGuard,
+    testing::TestKey,
+    InvalidReason, State, VerifyError,
+};
+use futures_util::FutureExt;
+use serde_json::json;
+
+type Handler = DiscordHandler<'static, State<'static>>;
+
+fn key() -> TestKey {
+    TestKey::from_seed([7; 32])
+}
+
+fn reply<T>(content: T) -> Result<InteractionResponse, DiscordError>
+where
+    T: Into<String>,
+{
+    Ok(InteractionResponse::new()
+        .response_type(InteractionCallbackType::ChannelMessageWithSource)
+        .data(InteractionCallbackData::new().content(content)))
+}
+
+fn content(res: Result<InteractionResponse, DiscordError>) -> String {
+    let res = res.unwrap_or_else(|e| panic!("request failed: {e}"));
+    res.data.and_then(|data| data.content).unwrap_or_default()
+}
+
+fn send(
+    handler: &Handler,
+    interaction: &InteractionBuilder,
+) -> Result<InteractionResponse, DiscordError> {
+    let key = key();
+    let signed = key.sign(interaction.to_json());
+
+    handler
+        .handle_request(
+            key.state(fixture::APPLICATION_ID),
+            signed.body,
+            signed.headers,
+        )
+        .now_or_never()
+        .expect("handlers do not wait on anything")
+}
+
+fn handler() -> Handler {
+    DiscordHandler::new()
+        .command(CommandHandler::new(
+            ApplicationCommand::new("echo").description("Echo"),
+            |_, interaction| async move {
+                let data = interaction.data.unwrap();
+
+                match data.options[0].raw_value() {
+                    Some(RawOptionValue::String(text)) => reply(text),
+                    value => reply(format!("{value:?}")),
+                }
+            },
+        ))
+        .subcommand(
+            ("config", "reset"),
+            SubcommandHandler::new(
+                ApplicationCommandOption::new("reset").description("Reset"),
+                |_, _, _| async { reply("reset") },
+            ),
+        )
+        .component((
+            CustomIdPattern::new("vote:{poll}:{choice}").unwrap(),
+            ComponentHandler::with_params(|_, _, params| async move {
+                reply(format!(
+                    "{} {}",
+                    params.get("poll").unwrap(),
+                    params.get("choice").unwrap()
+                ))
+            }),
+        ))
+        .component_fallback(ComponentHandler::new(|_, interaction| async move {
+            reply(interaction.data.unwrap().values.join(","))
+        }))
+        .modal(
+            "feedback",
+            ModalHandler::new(|_, interaction| async move {
+                reply(interaction.data.unwrap().component_value("body").unwrap())
+            }),
+        )
+        .autocomplete((
+            "search",
+            "query",
+            AutocompleteHandler::new(|_, _, focused| async move {
+                if focused.value.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let value = format!("{}!", focused.value);
+
+                Ok(vec![ApplicationCommandOptionChoice::new(
+                    value.clone(),
+                    ApplicationCommandOptionChoiceValue::String(value.into()),
+                )])
+            }),
+        ))
+}
+
+#[test]
+fn ping() {
+    let res = send(&handler(), &InteractionBuilder::ping()).unwrap();
+
+    assert_eq!(res.response_type, InteractionCallbackType::Pong);
+}
+
+#[test]
+fn commands() {
+    let handler = handler();
+
+    let echo =
+        InteractionBuilder::slash_command("echo").option(OptionFixture::string("text", "hi"));
+    assert_eq!(content(send(&handler, &echo)), "hi");
+
+    let reset =
+        InteractionBuilder::slash_command("config").option(OptionFixture::subcommand("reset", []));
+    assert_eq!(content(send(&handler, &reset)), "reset");
+
+    let unknown =
+        InteractionBuilder::slash_command("config").option(OptionFixture::subcommand("get", []));
+    assert!(matches!(
+        send(&handler, &unknown),
+        Err(DiscordError::UnknownCommand(name)) if name == "config get"
+    ));
+}
+
+#[test]
+fn components() {
+    let handler = handler();
+
+    assert_eq!(
+        content(send(&handler, &InteractionBuilder::button("vote:1:yes"))),
+        "1 yes"
+    );
+    assert_eq!(
+        content(send(
+            &handler,
+            &InteractionBuilder::select("colour", ["red", "blue"])
+        )),
+        "red,blue"
+    );
+}
+
+#[test]
+fn modals() {
+    let handler = handler();
+    let submit = InteractionBuilder::modal_submit("feedback").text_input("body", "thanks");
+
+    assert_eq!(content(send(&handler, &submit)), "thanks");
+    assert!(matches!(
+        send(&handler, &InteractionBuilder::modal_submit("other")),
+        Err(DiscordError::UnknownComponent(_))
+    ));
+}
+
+#[test]
+fn autocomplete() {
+    let handler = handler();
+    let search = |query: &str| {
+        InteractionBuilder::autocomplete("search")
+            .option(OptionFixture::string("query", query).focused())
+    };
+
+    let res = serde_json::to_value(send(&handler, &search("rus")).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        json!({
+            "type": 8,
+            "data": { "choices": [{ "name": "rus!", "value": "rus!" }] },
+        })
+    );
+
+    // No matches still answers with an empty list
+    let res = serde_json::to_value(send(&handler, &search("")).unwrap()).unwrap();
+    assert_eq!(res, json!({ "type": 8, "data": { "choices": [] } }));
+
+    let other = InteractionBuilder::autocomplete("search")
+        .option(OptionFixture::string("limit", "1").focused());
+    assert!(matches!(
+        send(&handler, &other),
+        Err(DiscordError::UnknownAutocomplete { command, option })
+            if command == "search" && option == "limit"
+    ));
+}
+
+#[test]
+fn requests_signed_by_another_key_are_rejected() {
+    let handler = handler();
+    let signed = TestKey::from_seed([8; 32]).sign(InteractionBuilder::ping().to_json());
+    let res = handler
+        .handle_request(
+            key().state(fixture::APPLICATION_ID),
+            signed.body,
+            signed.headers,
+        )
+        .now_or_never()
+        .unwrap();
+
+    assert!(matches!(
+        res,
+        Err(DiscordError::InvalidSignature(
+            InvalidReason::SignatureMismatch
+        ))
+    ));
+}
+
+#[test]
+fn tampered_bodies_are_rejected() {
+    let handler = handler();
+    let signed = key().sign(InteractionBuilder::ping().to_json());
+    let body = InteractionBuilder::slash_command("echo")
+        .option(OptionFixture::string("text", "hi"))
+        .to_json();
+    let res = handler
+        .handle_request(key().state(fixture::APPLICATION_ID), body, signed.headers)
+        .now_or_never()
+        .unwrap();
+
+    assert!(matches!(
+        res,
+        Err(DiscordError::InvalidSignature(
+            InvalidReason::SignatureMismatch
+        ))
+    ));
+}
+
+#[test]
+fn replayed_requests_are_rejected() {
+    let handler = handler().replay_guard(ReplayGuard::new(Duration::from_secs(5)).remember(8));
+    let ping = InteractionBuilder::ping();
+
+    assert!(send(&handler, &ping).is_ok());
+    assert!(matches!(
+        send(&handler, &ping),
+        Err(DiscordError::Replay(VerifyError::DuplicateInteraction))
+    ));
+    assert!(send(&handler, &ping.clone().id(Id::new(1))).is_ok());
+
+    let stale = key().sign_at(0, ping.id(Id::new(2)).to_json());
+    let res = handler
+        .handle_request(
+            key().state(fixture::APPLICATION_ID),
+            stale.body,
+            stale.headers,
+        )
+        .now_or_never()
+        .unwrap();
+
+    assert!(matches!(
+        res,
+        Err(DiscordError::Replay(VerifyError::StaleTimestamp))
+    ));
+}
+
+#[test]
+fn error_hook_answers_user_errors() {
+    let handler = handler()
+        .command(CommandHandler::new(
+            ApplicationCommand::new("roll").description("Roll"),
+            |_, _| async { Err(DiscordError::user("Pick a smaller number")) },
+        ))
+        .command(CommandHandler::new(
+            ApplicationCommand::new("crash").description("Crash"),
+            |_, _| async { Err(DiscordError::handler("database is down")) },
+        ))
+        .on_error(ephemeral_user_errors);
+
+    let res = send(&handler, &InteractionBuilder::slash_command("roll")).unwrap();
+    assert_eq!(
+        serde_json::to_value(res).unwrap(),
+        json!({
+            "type": 4,
+            "data": { "content": "Pick a smaller number", "flags": 64 },
+        })
+    );
+
+    assert!(matches!(
+        send(&handler, &InteractionBuilder::slash_command("crash")),
+        Err(DiscordError::Handler(_))
+    ));
+    assert!(matches!(
+        send(&handler, &InteractionBuilder::slash_command("missing")),
+        Err(DiscordError::UnknownCommand(_))
+    ));
+}
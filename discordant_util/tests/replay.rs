@@ -0,0 +1,148 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, UNIX_EPOCH},
+};
+
+use discordant_types::Id;
+use discordant_util::{replay::ReplayGuard, VerifyError};
+use http::{HeaderMap, HeaderValue};
+
+const NOW: u64 = 1_700_000_000;
+
+fn guard() -> ReplayGuard {
+    ReplayGuard::new(Duration::from_secs(5)).clock(|| UNIX_EPOCH + Duration::from_secs(NOW))
+}
+
+#[test]
+fn timestamps_within_skew() {
+    let guard = guard();
+
+    for timestamp in [NOW, NOW - 5, NOW + 5] {
+        assert!(guard.verify_timestamp(&timestamp.to_string()).is_ok());
+    }
+}
+
+#[test]
+fn stale_timestamps() {
+    let guard = guard();
+
+    assert!(matches!(
+        guard.verify_timestamp(&(NOW - 6).to_string()),
+        Err(VerifyError::StaleTimestamp)
+    ));
+    assert!(matches!(
+        guard.verify_timestamp("0"),
+        Err(VerifyError::StaleTimestamp)
+    ));
+}
+
+#[test]
+fn future_timestamps() {
+    let guard = guard();
+
+    assert!(matches!(
+        guard.verify_timestamp(&(NOW + 6).to_string()),
+        Err(VerifyError::StaleTimestamp)
+    ));
+}
+
+#[test]
+fn malformed_timestamps() {
+    let guard = guard();
+
+    for timestamp in ["", "abc", "-1", "1700000000.5", "99999999999999999999"] {
+        assert!(
+            matches!(
+                guard.verify_timestamp(timestamp),
+                Err(VerifyError::TimestampDecode)
+            ),
+            "`{timestamp}` should not decode"
+        );
+    }
+}
+
+#[test]
+fn timestamp_header() {
+    let guard = guard();
+    let mut headers = HeaderMap::new();
+
+    assert!(matches!(
+        guard.verify_headers(&headers),
+        Err(VerifyError::TimestampDecode)
+    ));
+
+    headers.insert(
+        "x-signature-timestamp",
+        HeaderValue::from_str(&NOW.to_string()).unwrap(),
+    );
+
+    assert!(guard.verify_headers(&headers).is_ok());
+}
+
+#[test]
+fn clock_is_read_on_every_check() {
+    let now = Arc::new(AtomicU64::new(NOW));
+    let guard = ReplayGuard::new(Duration::from_secs(5)).clock({
+        let now = now.clone();
+        move || UNIX_EPOCH + Duration::from_secs(now.load(Ordering::Relaxed))
+    });
+
+    assert!(guard.verify_timestamp(&NOW.to_string()).is_ok());
+
+    now.store(NOW + 60, Ordering::Relaxed);
+
+    assert!(matches!(
+        guard.verify_timestamp(&NOW.to_string()),
+        Err(VerifyError::StaleTimestamp)
+    ));
+}
+
+#[test]
+fn duplicate_interactions() {
+    let guard = guard().remember(2);
+
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+    assert!(guard.verify_interaction(Id::new(2)).is_ok());
+    assert!(matches!(
+        guard.verify_interaction(Id::new(1)),
+        Err(VerifyError::DuplicateInteraction)
+    ));
+    assert!(matches!(
+        guard.verify_interaction(Id::new(2)),
+        Err(VerifyError::DuplicateInteraction)
+    ));
+}
+
+#[test]
+fn oldest_interaction_is_evicted_at_capacity() {
+    let guard = guard().remember(2);
+
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+    assert!(guard.verify_interaction(Id::new(2)).is_ok());
+    assert!(guard.verify_interaction(Id::new(3)).is_ok());
+
+    assert!(guard.verify_interaction(Id::new(3)).is_err());
+
+    // 1 was forgotten to make room for 3
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+
+    // Which in turn pushed out 2, while 3 is still remembered
+    assert!(guard.verify_interaction(Id::new(3)).is_err());
+    assert!(guard.verify_interaction(Id::new(2)).is_ok());
+}
+
+#[test]
+fn interactions_are_not_remembered_by_default() {
+    let guard = guard();
+
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+
+    let guard = self::guard().remember(0);
+
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+    assert!(guard.verify_interaction(Id::new(1)).is_ok());
+}
@@ -0,0 +1,151 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use discordant_util::{
+    fixture::{self, InteractionBuilder},
+    handler::DiscordHandler,
+    service::DiscordService,
+    testing::TestKey,
+    State,
+};
+use futures_util::FutureExt;
+use http::{header, HeaderMap, Method, Request, Response, StatusCode};
+use http_body::{Body, Full};
+use tower_service::Service;
+
+fn key() -> TestKey {
+    TestKey::from_seed([7; 32])
+}
+
+fn service() -> DiscordService<State<'static>> {
+    DiscordHandler::new().into_service(key().state(fixture::APPLICATION_ID))
+}
+
+fn call<B>(service: &mut DiscordService<State<'static>>, req: Request<B>) -> Response<String>
+where
+    B: Body + Send + 'static,
+    B::Data: Send,
+{
+    service
+        .call(req)
+        .now_or_never()
+        .expect("nothing to wait on")
+        .unwrap()
+}
+
+fn ping() -> String {
+    InteractionBuilder::ping().to_json()
+}
+
+#[test]
+fn ping_is_answered_with_json() {
+    let res = call(&mut service(), key().sign(ping()).into_request());
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+    assert_eq!(res.body(), r#"{"type":1}"#);
+}
+
+#[test]
+fn only_post_is_allowed() {
+    for method in [Method::GET, Method::PUT, Method::DELETE] {
+        let mut req = key().sign(ping()).into_request();
+        *req.method_mut() = method;
+
+        assert_eq!(
+            call(&mut service(), req).status(),
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+    }
+}
+
+#[test]
+fn bad_signatures_are_unauthorized() {
+    let req = TestKey::from_seed([8; 32]).sign(ping()).into_request();
+    assert_eq!(call(&mut service(), req).status(), StatusCode::UNAUTHORIZED);
+
+    let mut req = key().sign(ping()).into_request();
+    *req.headers_mut() = HeaderMap::new();
+    assert_eq!(call(&mut service(), req).status(), StatusCode::UNAUTHORIZED);
+}
+
+#[test]
+fn bad_requests() {
+    // Signed, but not an interaction
+    let req = key().sign("{}").into_request();
+    assert_eq!(call(&mut service(), req).status(), StatusCode::BAD_REQUEST);
+
+    // Not UTF-8
+    let signed = key().sign(ping());
+    let mut req = Request::new(Full::new(Bytes::from_static(&[0xff, 0xfe])));
+    *req.method_mut() = Method::POST;
+    *req.headers_mut() = signed.headers;
+    assert_eq!(call(&mut service(), req).status(), StatusCode::BAD_REQUEST);
+
+    // The body fails to arrive
+    let mut req = Request::new(Broken);
+    *req.method_mut() = Method::POST;
+    assert_eq!(call(&mut service(), req).status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn unknown_commands_are_not_found() {
+    let req = key()
+        .sign(InteractionBuilder::slash_command("missing").to_json())
+        .into_request();
+
+    assert_eq!(call(&mut service(), req).status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn bodies_are_limited_to_a_mebibyte() {
+    const LIMIT: usize = 1024 * 1024;
+
+    // Trailing whitespace keeps the interaction valid
+    let padded = |len: usize| {
+        let ping = ping();
+        let padding = " ".repeat(len - ping.len());
+        ping + &padding
+    };
+
+    let req = key().sign(padded(LIMIT)).into_request();
+    assert_eq!(call(&mut service(), req).status(), StatusCode::OK);
+
+    let req = key().sign(padded(LIMIT + 1)).into_request();
+    assert_eq!(
+        call(&mut service(), req).status(),
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+
+    let req = key().sign(ping()).into_request();
+    assert_eq!(
+        call(&mut service().body_limit(8), req).status(),
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+}
+
+/// A body whose connection drops before any data arrives.
+struct Broken;
+
+impl Body for Broken {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Poll::Ready(Some(Err(io::ErrorKind::ConnectionReset.into())))
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+}
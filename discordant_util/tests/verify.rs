@@ -0,0 +1,369 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+use discordant_util::{
+    discord_verify, verify_signature, DiscordState, DiscordVerify, InvalidReason, State, Verifier,
+    VerifyError,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use http::{HeaderMap, HeaderValue};
+
+const TIMESTAMP: &str = "1700000000";
+const BODY: &str = r#"{"type":1}"#;
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn public_key(key: &SigningKey) -> String {
+    hex(key.verifying_key().as_bytes())
+}
+
+fn sign(key: &SigningKey, timestamp: &str, body: &str) -> String {
+    hex(&key.sign(format!("{timestamp}{body}").as_bytes()).to_bytes())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+fn headers(timestamp: &str, signature: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "x-signature-timestamp",
+        HeaderValue::from_str(timestamp).unwrap(),
+    );
+    headers.insert(
+        "x-signature-ed25519",
+        HeaderValue::from_str(signature).unwrap(),
+    );
+    headers
+}
+
+fn verify<'a, S>(state: &S, headers: HeaderMap) -> Result<usize, InvalidReason>
+where
+    S: DiscordState<'a>,
+{
+    match discord_verify(state, BODY.as_bytes(), &headers) {
+        DiscordVerify::Valid(index) => Ok(index),
+        DiscordVerify::Invalid(reason) => Err(reason),
+    }
+}
+
+#[test]
+fn valid_request() {
+    let key = signing_key(1);
+    let state = State::new(public_key(&key), "1").unwrap();
+
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&key, TIMESTAMP, BODY))),
+        Ok(0)
+    );
+
+    // Hex digits are accepted in either case
+    let upper = sign(&key, TIMESTAMP, BODY).to_uppercase();
+    assert_eq!(verify(&state, headers(TIMESTAMP, &upper)), Ok(0));
+}
+
+#[test]
+fn missing_headers() {
+    let key = signing_key(1);
+    let state = State::new(public_key(&key), "1").unwrap();
+    let signed = headers(TIMESTAMP, &sign(&key, TIMESTAMP, BODY));
+
+    for name in ["x-signature-timestamp", "x-signature-ed25519"] {
+        let mut headers = signed.clone();
+        headers.remove(name);
+
+        assert_eq!(
+            verify(&state, headers),
+            Err(InvalidReason::MissingHeader(name))
+        );
+    }
+
+    assert_eq!(
+        verify(&state, HeaderMap::new()),
+        Err(InvalidReason::MissingHeader("x-signature-timestamp"))
+    );
+}
+
+#[test]
+fn malformed_signatures() {
+    let key = signing_key(1);
+    let state = State::new(public_key(&key), "1").unwrap();
+    let signature = sign(&key, TIMESTAMP, BODY);
+
+    for signature in [
+        "",
+        &signature[..127],
+        &signature[..126],
+        &format!("{signature}00"),
+        &format!("{}zz", &signature[..126]),
+        &"0x".repeat(64),
+    ] {
+        assert_eq!(
+            verify(&state, headers(TIMESTAMP, signature)),
+            Err(InvalidReason::MalformedSignature),
+            "`{signature}` should be malformed"
+        );
+    }
+
+    // 128 bytes, but not 128 hex digits
+    let mut headers = headers(TIMESTAMP, &signature);
+    headers.insert(
+        "x-signature-ed25519",
+        HeaderValue::from_bytes("é".repeat(64).as_bytes()).unwrap(),
+    );
+
+    assert_eq!(
+        verify(&state, headers),
+        Err(InvalidReason::MalformedSignature)
+    );
+}
+
+#[test]
+fn signature_mismatch() {
+    let key = signing_key(1);
+    let state = State::new(public_key(&key), "1").unwrap();
+
+    // Signed by another key
+    let other = sign(&signing_key(2), TIMESTAMP, BODY);
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &other)),
+        Err(InvalidReason::SignatureMismatch)
+    );
+
+    // Signed over a different timestamp or body
+    let signature = sign(&key, "1700000001", BODY);
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &signature)),
+        Err(InvalidReason::SignatureMismatch)
+    );
+
+    let signature = sign(&key, TIMESTAMP, "{}");
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &signature)),
+        Err(InvalidReason::SignatureMismatch)
+    );
+}
+
+#[test]
+fn bad_keys() {
+    assert!(matches!(
+        State::new("not hex", "1"),
+        Err(VerifyError::PublicKeyDecode)
+    ));
+    assert!(matches!(
+        State::new("00".repeat(31), "1"),
+        Err(VerifyError::PublicKeyDecode)
+    ));
+    assert!(matches!(
+        Verifier::new(&"é".repeat(32)),
+        Err(VerifyError::PublicKeyDecode)
+    ));
+
+    // Valid hex, but not a point on the curve
+    assert!(matches!(
+        Verifier::new(&format!("02{}", "00".repeat(31))),
+        Err(VerifyError::PublicKeyFromBytes)
+    ));
+}
+
+#[test]
+fn bad_key_is_reported() {
+    let key = signing_key(1);
+    let state = KeyState("not hex");
+
+    assert!(matches!(
+        discord_verify(
+            &state,
+            BODY.as_bytes(),
+            &headers(TIMESTAMP, &sign(&key, TIMESTAMP, BODY))
+        ),
+        DiscordVerify::Invalid(InvalidReason::BadKey)
+    ));
+}
+
+#[test]
+fn verifier() {
+    let key = signing_key(1);
+    let verifier = Verifier::new(&public_key(&key)).unwrap();
+    let signature = sign(&key, TIMESTAMP, BODY);
+
+    assert!(verifier
+        .verify(&headers(TIMESTAMP, &signature), BODY.as_bytes())
+        .unwrap());
+    assert!(!verifier
+        .verify(&headers(TIMESTAMP, &signature), b"{}")
+        .unwrap());
+    assert!(!verifier.verify(&HeaderMap::new(), BODY.as_bytes()).unwrap());
+
+    assert!(verifier
+        .verify_parts(TIMESTAMP.as_bytes(), signature.as_bytes(), BODY.as_bytes())
+        .unwrap());
+    assert!(matches!(
+        verifier.verify_parts(
+            TIMESTAMP.as_bytes(),
+            &signature.as_bytes()[..127],
+            BODY.as_bytes()
+        ),
+        Err(VerifyError::SignatureDecode)
+    ));
+}
+
+#[test]
+fn legacy_verify_signature() {
+    let key = signing_key(1);
+    let signature = sign(&key, TIMESTAMP, BODY);
+    let public_key = public_key(&key);
+    let headers = HashMap::from([
+        ("x-signature-timestamp", TIMESTAMP),
+        ("x-signature-ed25519", signature.as_str()),
+    ]);
+
+    assert!(verify_signature(&headers, BODY, &public_key).unwrap());
+    assert!(!verify_signature(&headers, "{}", &public_key).unwrap());
+    assert!(!verify_signature(&HashMap::new(), BODY, &public_key).unwrap());
+    assert!(matches!(
+        verify_signature(&headers, BODY, "not hex"),
+        Err(VerifyError::PublicKeyDecode)
+    ));
+
+    let odd = HashMap::from([
+        ("x-signature-timestamp", TIMESTAMP),
+        ("x-signature-ed25519", &signature[..127]),
+    ]);
+
+    assert!(matches!(
+        verify_signature(&odd, BODY, &public_key),
+        Err(VerifyError::SignatureDecode)
+    ));
+}
+
+/// Decodes its key on every request, so a bad key only shows up at verification.
+struct KeyState(&'static str);
+
+impl<'a> DiscordState<'a> for KeyState {
+    fn public_key(&self) -> Cow<'a, str> {
+        Cow::Borrowed(self.0)
+    }
+
+    fn application_id(&self) -> Cow<'a, str> {
+        Cow::Borrowed("1")
+    }
+}
+
+/// Holds several keys, decoded on every request unless `verifiers` is set.
+struct Keys {
+    public_keys: Vec<String>,
+    verifiers: Vec<Verifier>,
+}
+
+impl Keys {
+    fn new(public_keys: &[&str]) -> Self {
+        Self {
+            public_keys: public_keys.iter().map(|key| key.to_string()).collect(),
+            verifiers: Vec::new(),
+        }
+    }
+
+    fn decoded(public_keys: &[&str]) -> Self {
+        Self {
+            public_keys: Vec::new(),
+            verifiers: public_keys
+                .iter()
+                .map(|key| Verifier::new(key).unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl<'a> DiscordState<'a> for Keys {
+    fn public_key(&self) -> Cow<'a, str> {
+        Cow::Owned(self.public_keys[0].clone())
+    }
+
+    fn application_id(&self) -> Cow<'a, str> {
+        Cow::Borrowed("1")
+    }
+
+    fn public_keys(&self) -> Vec<Cow<'a, str>> {
+        self.public_keys.iter().cloned().map(Cow::Owned).collect()
+    }
+
+    fn verifiers(&self) -> &[Verifier] {
+        &self.verifiers
+    }
+}
+
+#[test]
+fn matching_key_index() {
+    let old = signing_key(1);
+    let new = signing_key(2);
+    let state = Keys::new(&[&public_key(&old), &public_key(&new)]);
+
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&old, TIMESTAMP, BODY))),
+        Ok(0)
+    );
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&new, TIMESTAMP, BODY))),
+        Ok(1)
+    );
+    assert_eq!(
+        verify(
+            &state,
+            headers(TIMESTAMP, &sign(&signing_key(3), TIMESTAMP, BODY))
+        ),
+        Err(InvalidReason::SignatureMismatch)
+    );
+}
+
+#[test]
+fn bad_keys_are_skipped() {
+    let key = signing_key(1);
+    let not_a_point = format!("02{}", "00".repeat(31));
+    let state = Keys::new(&["not hex", &not_a_point, &public_key(&key)]);
+
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&key, TIMESTAMP, BODY))),
+        Ok(2)
+    );
+
+    // A bad key only matters once no other key matched
+    assert_eq!(
+        verify(
+            &state,
+            headers(TIMESTAMP, &sign(&signing_key(2), TIMESTAMP, BODY))
+        ),
+        Err(InvalidReason::BadKey)
+    );
+
+    let state = Keys::new(&["not hex", &not_a_point]);
+
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&key, TIMESTAMP, BODY))),
+        Err(InvalidReason::BadKey)
+    );
+}
+
+#[test]
+fn verifiers_are_preferred_over_public_keys() {
+    let old = signing_key(1);
+    let new = signing_key(2);
+    let state = Keys::decoded(&[&public_key(&old), &public_key(&new)]);
+
+    assert_eq!(
+        verify(&state, headers(TIMESTAMP, &sign(&new, TIMESTAMP, BODY))),
+        Ok(1)
+    );
+    assert_eq!(
+        verify(
+            &state,
+            headers(TIMESTAMP, &sign(&signing_key(3), TIMESTAMP, BODY))
+        ),
+        Err(InvalidReason::SignatureMismatch)
+    );
+}
@@ -1,2 +1,30 @@
+#[cfg(feature = "http")]
+pub use discordant_http as http;
+/// Derives for describing commands as types. Generated code is pointed at this crate with
+/// `#[command(crate = "discordant::types")]`:
+///
+/// ```
+/// use discordant::macros::CommandOptions;
+///
+/// #[derive(CommandOptions)]
+/// #[command(crate = "discordant::types")]
+/// struct Ping {
+///     /// Text to echo back
+///     text: String,
+/// }
+/// ```
+///
+/// Every option needs a description, from a doc comment or `description = "..."`:
+///
+/// ```compile_fail
+/// use discordant::macros::CommandOptions;
+///
+/// #[derive(CommandOptions)]
+/// #[command(crate = "discordant::types")]
+/// struct Ping {
+///     text: String,
+/// }
+/// ```
+pub use discordant_macros as macros;
 pub use discordant_types as types;
 pub use discordant_util as util;
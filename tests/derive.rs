@@ -0,0 +1,219 @@
+use discordant::{
+    macros::{CommandGroup, CommandOptions},
+    types::{
+        Channel, CommandGroup as _, CommandOptions as _, Id, Interaction, InteractionData,
+        OptionError, User,
+    },
+};
+use serde_json::json;
+
+#[derive(CommandOptions, Debug)]
+#[command(crate = "discordant::types")]
+struct Ban<'a> {
+    /// Reason shown in the audit log
+    #[command(max_length = 512)]
+    reason: Option<&'a str>,
+    /// Who to ban
+    user: &'a str,
+    #[command(
+        description = "Days of messages to delete",
+        min_value = 0,
+        max_value = 7,
+        choice(name = "None", value = 0),
+        choice(name = "Week", value = 7)
+    )]
+    days: i64,
+    /// Where to announce it
+    #[command(rename = "log-channel", channel_types(GuildText, GuildNews))]
+    log_channel: Option<&'a Channel>,
+    /// How strict to be
+    #[command(min_value = 0.5, autocomplete)]
+    strictness: Option<f64>,
+}
+
+fn data(options: serde_json::Value) -> InteractionData {
+    serde_json::from_value(json!({ "name": "ban", "type": 1, "options": options })).unwrap()
+}
+
+#[test]
+fn options_schema() {
+    let options = serde_json::to_value(Ban::options()).unwrap();
+
+    // Required options are moved ahead of optional ones, otherwise in field order
+    assert_eq!(
+        options,
+        json!([
+            {
+                "type": 3,
+                "name": "user",
+                "description": "Who to ban",
+                "required": true
+            },
+            {
+                "type": 4,
+                "name": "days",
+                "description": "Days of messages to delete",
+                "required": true,
+                "min_value": 0,
+                "max_value": 7,
+                "choices": [
+                    { "name": "None", "value": 0 },
+                    { "name": "Week", "value": 7 }
+                ]
+            },
+            {
+                "type": 3,
+                "name": "reason",
+                "description": "Reason shown in the audit log",
+                "required": false,
+                "max_length": 512
+            },
+            {
+                "type": 7,
+                "name": "log-channel",
+                "description": "Where to announce it",
+                "required": false,
+                "channel_types": [0, 5]
+            },
+            {
+                "type": 10,
+                "name": "strictness",
+                "description": "How strict to be",
+                "required": false,
+                "min_value": 0.5,
+                "autocomplete": true
+            }
+        ])
+    );
+}
+
+#[test]
+fn options_parse() {
+    let data = data(json!([
+        { "name": "user", "type": 3, "value": "nelly" },
+        { "name": "days", "type": 4, "value": 7 },
+        { "name": "strictness", "type": 10, "value": 1.5 }
+    ]));
+
+    let ban = Ban::from_data(&data).unwrap();
+
+    assert_eq!(ban.user, "nelly");
+    assert_eq!(ban.days, 7);
+    assert_eq!(ban.reason, None);
+    assert!(ban.log_channel.is_none());
+    assert_eq!(ban.strictness, Some(1.5));
+}
+
+#[test]
+fn options_parse_missing_required() {
+    let data = data(json!([{ "name": "user", "type": 3, "value": "nelly" }]));
+
+    match Ban::from_data(&data).unwrap_err() {
+        OptionError::Missing(name) => assert_eq!(name, "days"),
+        error => panic!("unexpected error: {error:?}"),
+    }
+}
+
+/// Manage permissions
+#[derive(CommandGroup, Debug)]
+#[command(crate = "discordant::types")]
+enum Permissions<'a> {
+    /// Edit or view permissions for a user
+    #[command(group)]
+    User(UserPermissions<'a>),
+    /// Show the permissions of a role
+    ShowRole {
+        /// Name of the role
+        name: &'a str,
+    },
+    /// Reset every permission
+    #[command(name = "reset-all")]
+    Reset,
+}
+
+/// Edit or view permissions for a user
+#[derive(CommandGroup, Debug)]
+#[command(crate = "discordant::types")]
+enum UserPermissions<'a> {
+    /// Get permissions for a user
+    Get(GetUser<'a>),
+}
+
+#[derive(CommandOptions, Debug)]
+#[command(crate = "discordant::types")]
+struct GetUser<'a> {
+    /// The user to get
+    user: &'a User,
+    /// The channel permissions to get
+    channel: Option<&'a Channel>,
+}
+
+#[test]
+fn group_schema() {
+    let command = serde_json::to_value(Permissions::command()).unwrap();
+
+    assert_eq!(command["name"], "permissions");
+    assert_eq!(command["description"], "Manage permissions");
+
+    let options = command["options"].as_array().unwrap();
+    let names = options.iter().map(|o| &o["name"]).collect::<Vec<_>>();
+    assert_eq!(names, ["user", "show_role", "reset-all"]);
+
+    // A subcommand group holding a subcommand
+    assert_eq!(options[0]["type"], 2);
+    assert_eq!(options[0]["options"][0]["type"], 1);
+    assert_eq!(options[0]["options"][0]["name"], "get");
+    assert_eq!(options[0]["options"][0]["options"][0]["name"], "user");
+    assert_eq!(options[0]["options"][0]["options"][0]["type"], 6);
+
+    // Subcommands with inline and no options
+    assert_eq!(options[1]["type"], 1);
+    assert_eq!(options[1]["options"][0]["name"], "name");
+    assert_eq!(options[2]["type"], 1);
+    assert!(options[2].get("options").is_none());
+}
+
+#[test]
+fn group_dispatch() {
+    let interaction: Interaction = serde_json::from_str(include_str!(
+        "../discordant_types/tests/payloads/interaction/subcommand_group.json"
+    ))
+    .unwrap();
+
+    let command = Permissions::from_data(interaction.data.as_ref().unwrap()).unwrap();
+
+    match command {
+        Permissions::User(UserPermissions::Get(get)) => {
+            assert_eq!(get.user.id, Id::new(80351110224678912));
+            assert_eq!(get.channel.map(|c| c.id), Some(Id::new(772908445358620702)));
+        }
+        command => panic!("unexpected command: {command:?}"),
+    }
+
+    let show_role = data(json!([{
+        "name": "show_role",
+        "type": 1,
+        "options": [{ "name": "name", "type": 3, "value": "mods" }]
+    }]));
+
+    match Permissions::from_data(&show_role).unwrap() {
+        Permissions::ShowRole { name } => assert_eq!(name, "mods"),
+        command => panic!("unexpected command: {command:?}"),
+    }
+
+    let data = data(json!([{ "name": "reset-all", "type": 1, "options": [] }]));
+    assert!(matches!(
+        Permissions::from_data(&data),
+        Ok(Permissions::Reset)
+    ));
+}
+
+#[test]
+fn group_unknown_subcommand() {
+    let data = data(json!([{ "name": "missing", "type": 1, "options": [] }]));
+
+    match Permissions::from_data(&data).unwrap_err() {
+        OptionError::UnknownSubcommand(path) => assert_eq!(path, "missing"),
+        error => panic!("unexpected error: {error:?}"),
+    }
+}